@@ -1,6 +1,28 @@
 #![no_std]
 
-use soroban_sdk::{contract, contractimpl, contracttype, Address, Env, String, Vec, symbol_short};
+use soroban_sdk::{contract, contracterror, contractimpl, contracttype, token, Address, Bytes, BytesN, Env, EnvBase, IntoVal, String, Val, Vec, symbol_short};
+
+// Contract errors, numbered 1xx (the expert registry uses 2xx and review consensus 3xx)
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    AlreadyInitialized = 101,
+    NotInitialized = 102,
+    NotAuthorized = 103,
+    ClaimNotFound = 104,
+    ReviewContractNotSet = 105,
+    InvalidAmount = 106,
+    ClaimFinalized = 107,
+    ClaimUnderReview = 108,
+    DuplicateClaim = 110,
+    CategoryNotFound = 111,
+    CategoryExists = 112,
+    CategoryRetired = 113,
+    InvalidSlug = 114,
+    InvalidCategoryParams = 115,
+    EmptyClaim = 116,
+}
 
 // Claim status enumeration
 #[contracttype]
@@ -10,6 +32,34 @@ pub enum ClaimStatus {
     UnderReview,
     True,
     False,
+    Inconclusive, // Reviews failed to reach the supermajority
+    Misleading,
+    Unverifiable,
+    PartiallyTrue,
+    Withdrawn, // Cancelled by the submitter before any review
+    Expired,   // Never reached consensus; sponsors refunded
+}
+
+// Expert level as defined by the expert registry
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ExpertLevel {
+    General,
+    Specialized,
+    Professional,
+}
+
+// Claim category and the rules for reviewing its claims
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct Category {
+    pub slug: String, // Lowercase a-z, 0-9 and '-'; stored on claims and expert profiles
+    pub name: String,
+    pub description: String,
+    pub claim_fee: i128,
+    pub min_reviews: u32,
+    pub min_expert_level: ExpertLevel,
+    pub active: bool, // Retired categories accept no new claims or experts
 }
 
 // Claim data structure
@@ -25,6 +75,28 @@ pub struct Claim {
     pub stake_pool: i128,
     pub timestamp: u64,
     pub review_count: u32,
+    pub version: u32, // Latest version; amendments bump it
+}
+
+// Snapshot of a claim's content at one version
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct ClaimVersion {
+    pub claim_id: u64,
+    pub version: u32,
+    pub text: String,
+    pub category: String,
+    pub sources: Vec<String>,
+    pub timestamp: u64,
+}
+
+// Secondary index over claims
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ClaimIndex {
+    Submitter(Address),
+    Category(String),
+    Status(ClaimStatus),
 }
 
 // Storage keys
@@ -33,73 +105,201 @@ pub enum DataKey {
     ClaimCount,
     Claim(u64),
     Admin,
-    ClaimFee,
+    Token,
     ExpertRegistryContract,
     ReviewConsensusContract,
+    Contribution(u64, Address), // Amount a sponsor has put into a claim's stake pool
+    ClaimSponsors(u64),
+    ClaimVersion(u64, u32),     // Maps (claim_id, version) -> ClaimVersion
+    ClaimHash(BytesN<32>),      // Maps content hash of the normalized text -> claim_id
+    IndexSize(ClaimIndex),          // Maps index -> number of claims in it
+    IndexEntry(ClaimIndex, u32),    // Maps (index, position) -> claim_id
+    IndexPosition(ClaimIndex, u64), // Maps (index, claim_id) -> position
+    Category(String),           // Maps slug -> Category
+    Categories,                 // Vec of every slug ever created
 }
 
-// Submission fee in stroops (0.5 XLM = 5_000_000 stroops)
-const CLAIM_FEE: i128 = 5_000_000;
+// Claim text is read this many bytes at a time when hashed
+const TEXT_CHUNK_SIZE: usize = 256;
+
+// Longest category slug accepted, in bytes
+const MAX_SLUG_LENGTH: u32 = 32;
+
+// Storage TTLs in ledgers (~5s per ledger)
+const DAY_IN_LEDGERS: u32 = 17_280;
+const INSTANCE_BUMP_AMOUNT: u32 = 7 * DAY_IN_LEDGERS;
+const INSTANCE_LIFETIME_THRESHOLD: u32 = INSTANCE_BUMP_AMOUNT - DAY_IN_LEDGERS;
+const PERSISTENT_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+const PERSISTENT_LIFETIME_THRESHOLD: u32 = PERSISTENT_BUMP_AMOUNT - DAY_IN_LEDGERS;
 
 #[contract]
 pub struct ClaimRegistry;
 
 #[contractimpl]
 impl ClaimRegistry {
-    /// Initialize the contract with admin
-    pub fn initialize(env: Env, admin: Address) {
+    /// Initialize the contract with admin and the token used for claim fees
+    pub fn initialize(env: Env, admin: Address, token: Address) -> Result<(), Error> {
         if env.storage().instance().has(&DataKey::Admin) {
-            panic!("Already initialized");
+            return Err(Error::AlreadyInitialized);
         }
-        
+
         env.storage().instance().set(&DataKey::Admin, &admin);
         env.storage().instance().set(&DataKey::ClaimCount, &0u64);
-        env.storage().instance().set(&DataKey::ClaimFee, &CLAIM_FEE);
+        env.storage().instance().set(&DataKey::Token, &token);
+        Self::extend_instance_ttl(&env);
+
+        Ok(())
     }
 
     /// Set the expert registry contract address
-    pub fn set_expert_registry(env: Env, admin: Address, expert_contract: Address) {
-        admin.require_auth();
-        
-        let stored_admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
-        if admin != stored_admin {
-            panic!("Not authorized");
-        }
-        
+    pub fn set_expert_registry(env: Env, admin: Address, expert_contract: Address) -> Result<(), Error> {
+        Self::require_admin(&env, &admin)?;
+
         env.storage().instance().set(&DataKey::ExpertRegistryContract, &expert_contract);
+        Self::extend_instance_ttl(&env);
+
+        Ok(())
     }
 
     /// Set the review consensus contract address
-    pub fn set_review_consensus(env: Env, admin: Address, review_contract: Address) {
-        admin.require_auth();
-        
-        let stored_admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
-        if admin != stored_admin {
-            panic!("Not authorized");
-        }
-        
+    pub fn set_review_consensus(env: Env, admin: Address, review_contract: Address) -> Result<(), Error> {
+        Self::require_admin(&env, &admin)?;
+
         env.storage().instance().set(&DataKey::ReviewConsensusContract, &review_contract);
+        Self::extend_instance_ttl(&env);
+
+        Ok(())
     }
 
-    /// Submit a new claim with 0.5 XLM fee
+    /// Create a claim category with its submission fee and review rules
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_category(
+        env: Env,
+        admin: Address,
+        slug: String,
+        name: String,
+        description: String,
+        claim_fee: i128,
+        min_reviews: u32,
+        min_expert_level: ExpertLevel,
+    ) -> Result<(), Error> {
+        Self::require_admin(&env, &admin)?;
+        Self::validate_slug(&slug)?;
+
+        let key = DataKey::Category(slug.clone());
+        if env.storage().persistent().has(&key) {
+            return Err(Error::CategoryExists);
+        }
+
+        let category = Category {
+            slug: slug.clone(),
+            name,
+            description,
+            claim_fee,
+            min_reviews,
+            min_expert_level,
+            active: true,
+        };
+        Self::write_category(&env, &category)?;
+
+        let mut slugs: Vec<String> = env.storage().persistent().get(&DataKey::Categories).unwrap_or(Vec::new(&env));
+        slugs.push_back(slug.clone());
+        env.storage().persistent().set(&DataKey::Categories, &slugs);
+        env.storage().persistent().extend_ttl(&DataKey::Categories, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+
+        env.events().publish((symbol_short!("category"),), slug);
+
+        Ok(())
+    }
+
+    /// Update a category's description, submission fee and review rules
+    pub fn update_category(
+        env: Env,
+        admin: Address,
+        slug: String,
+        description: String,
+        claim_fee: i128,
+        min_reviews: u32,
+        min_expert_level: ExpertLevel,
+    ) -> Result<(), Error> {
+        Self::require_admin(&env, &admin)?;
+
+        let mut category = Self::read_category(&env, &slug).ok_or(Error::CategoryNotFound)?;
+        category.description = description;
+        category.claim_fee = claim_fee;
+        category.min_reviews = min_reviews;
+        category.min_expert_level = min_expert_level;
+        Self::write_category(&env, &category)?;
+
+        Ok(())
+    }
+
+    /// Retire a category; its existing claims are unaffected
+    pub fn retire_category(env: Env, admin: Address, slug: String) -> Result<(), Error> {
+        Self::require_admin(&env, &admin)?;
+
+        let mut category = Self::read_category(&env, &slug).ok_or(Error::CategoryNotFound)?;
+        category.active = false;
+        Self::write_category(&env, &category)?;
+
+        env.events().publish((symbol_short!("cat_ret"),), slug);
+
+        Ok(())
+    }
+
+    /// Get a category by slug
+    pub fn get_category(env: Env, slug: String) -> Option<Category> {
+        Self::read_category(&env, &slug)
+    }
+
+    /// Get every category, retired ones included
+    pub fn get_categories(env: Env) -> Vec<Category> {
+        let slugs: Vec<String> = env.storage().persistent().get(&DataKey::Categories).unwrap_or(Vec::new(&env));
+        let mut categories = Vec::new(&env);
+
+        for slug in slugs.iter() {
+            if let Some(category) = Self::read_category(&env, &slug) {
+                categories.push_back(category);
+            }
+        }
+
+        categories
+    }
+
+    /// Submit a new claim in an active category, paying the category's fee
     pub fn submit_claim(
         env: Env,
         submitter: Address,
         text: String,
         category: String,
         sources: Vec<String>,
-    ) -> u64 {
+    ) -> Result<u64, Error> {
         submitter.require_auth();
 
-        // Transfer the claim fee (0.5 XLM) from submitter to contract
-        let fee: i128 = env.storage().instance().get(&DataKey::ClaimFee).unwrap_or(CLAIM_FEE);
-        
-        // Get token contract for native XLM
-        let token_address = Address::from_string(&String::from_str(&env, "CDLZFC3SYJYDZT7K67VZ75HPJVIEUVNIXF47ZG2FB2RMQQVU2HHGCYSC"));
-        
-        // Note: In production, use proper token transfer via Stellar Asset Contract
-        // For now, we add the fee to the stake pool
-        
+        // Inputs are validated before the duplicate lookup, so an invalid submission never links to a claim
+        let fee = Self::require_active_category(&env, &category)?.claim_fee;
+        let content_hash = Self::content_hash(&env, &text);
+        Self::require_text(&env, &content_hash)?;
+
+        // Resubmitting a known claim links to it instead of charging another fee
+        if let Some(existing_id) = Self::find_duplicate(&env, &content_hash) {
+            env.events().publish((symbol_short!("duplicate"), existing_id), submitter);
+            return Ok(existing_id);
+        }
+
+        // Transfer the category's claim fee from submitter to contract
+        let token_address: Address = env.storage().instance()
+            .get(&DataKey::Token)
+            .ok_or(Error::NotInitialized)?;
+
+        if fee > 0 {
+            token::Client::new(&env, &token_address).transfer(
+                &submitter,
+                &env.current_contract_address(),
+                &fee,
+            );
+        }
+
         // Increment claim counter
         let mut claim_count: u64 = env.storage().instance().get(&DataKey::ClaimCount).unwrap_or(0);
         claim_count += 1;
@@ -114,20 +314,107 @@ impl ClaimRegistry {
             stake_pool: fee,
             timestamp: env.ledger().timestamp(),
             review_count: 0,
+            version: 1,
         };
 
-        env.storage().instance().set(&DataKey::Claim(claim_count), &claim);
+        Self::write_claim(&env, &claim);
+        Self::write_version(&env, &claim);
+        Self::write_content_hash(&env, &content_hash, claim_count);
         env.storage().instance().set(&DataKey::ClaimCount, &claim_count);
 
+        Self::index_add(&env, &ClaimIndex::Submitter(claim.submitter.clone()), claim_count);
+        Self::index_add(&env, &ClaimIndex::Category(claim.category.clone()), claim_count);
+        Self::index_add(&env, &ClaimIndex::Status(claim.status.clone()), claim_count);
+
+        // The submitter's fee is the claim's first contribution
+        if fee > 0 {
+            Self::record_contribution(&env, claim_count, &submitter, fee);
+        }
+
         // Emit event
         env.events().publish((symbol_short!("claim_sub"),), claim_count);
 
-        claim_count
+        Ok(claim_count)
+    }
+
+    /// Amend a pending, unreviewed claim, keeping the previous versions
+    pub fn amend_claim(
+        env: Env,
+        submitter: Address,
+        claim_id: u64,
+        text: String,
+        sources: Vec<String>,
+    ) -> Result<u32, Error> {
+        submitter.require_auth();
+
+        let mut claim = Self::read_claim(&env, claim_id).ok_or(Error::ClaimNotFound)?;
+        Self::require_unreviewed(&claim, &submitter)?;
+
+        // The amended text must not turn the claim into a copy of another one
+        let content_hash = Self::content_hash(&env, &text);
+        Self::require_text(&env, &content_hash)?;
+        match Self::find_duplicate(&env, &content_hash) {
+            Some(existing_id) if existing_id != claim_id => return Err(Error::DuplicateClaim),
+            _ => {}
+        }
+
+        let previous_key = DataKey::ClaimHash(Self::content_hash(&env, &claim.text));
+        if env.storage().persistent().get(&previous_key) == Some(claim_id) {
+            env.storage().persistent().remove(&previous_key);
+        }
+        Self::write_content_hash(&env, &content_hash, claim_id);
+
+        // The category is fixed at submission, since it sets the fee the claim paid
+        claim.text = text;
+        claim.sources = sources;
+        claim.version += 1;
+
+        Self::write_claim(&env, &claim);
+        Self::write_version(&env, &claim);
+
+        env.events().publish((symbol_short!("amended"), claim_id), claim.version);
+
+        Ok(claim.version)
     }
 
     /// Get a claim by ID
     pub fn get_claim(env: Env, claim_id: u64) -> Option<Claim> {
-        env.storage().instance().get(&DataKey::Claim(claim_id))
+        Self::read_claim(&env, claim_id)
+    }
+
+    /// Hash of a claim text after normalization, as used for duplicate detection
+    pub fn compute_content_hash(env: Env, text: String) -> BytesN<32> {
+        Self::content_hash(&env, &text)
+    }
+
+    /// Find the claim holding the given content hash: one still open, or finalized with a verdict
+    pub fn find_claim_by_hash(env: Env, content_hash: BytesN<32>) -> Option<u64> {
+        Self::find_duplicate(&env, &content_hash)
+    }
+
+    /// Get one version of a claim's content
+    pub fn get_claim_version(env: Env, claim_id: u64, version: u32) -> Option<ClaimVersion> {
+        env.storage().persistent().get(&DataKey::ClaimVersion(claim_id, version))
+    }
+
+    /// Get every version of a claim's content, oldest first
+    pub fn get_claim_history(env: Env, claim_id: u64) -> Vec<ClaimVersion> {
+        let mut history = Vec::new(&env);
+
+        if let Some(claim) = Self::read_claim(&env, claim_id) {
+            for version in 1..=claim.version {
+                if let Some(snapshot) = Self::get_claim_version(env.clone(), claim_id, version) {
+                    history.push_back(snapshot);
+                }
+            }
+        }
+
+        history
+    }
+
+    /// Get the token used for claim fees
+    pub fn get_token(env: Env) -> Result<Address, Error> {
+        env.storage().instance().get(&DataKey::Token).ok_or(Error::NotInitialized)
     }
 
     /// Get total number of claims
@@ -136,65 +423,126 @@ impl ClaimRegistry {
     }
 
     /// Update claim status (called by review consensus contract)
-    pub fn update_claim_status(env: Env, caller: Address, claim_id: u64, new_status: ClaimStatus) {
-        caller.require_auth();
-
-        // Verify caller is the review consensus contract
-        let review_contract: Address = env.storage().instance()
-            .get(&DataKey::ReviewConsensusContract)
-            .expect("Review consensus contract not set");
-
-        if caller != review_contract {
-            panic!("Only review consensus contract can update status");
-        }
+    pub fn update_claim_status(env: Env, caller: Address, claim_id: u64, new_status: ClaimStatus) -> Result<(), Error> {
+        Self::require_review_consensus(&env, &caller)?;
 
-        let mut claim: Claim = env.storage().instance()
-            .get(&DataKey::Claim(claim_id))
-            .expect("Claim not found");
+        let mut claim = Self::read_claim(&env, claim_id).ok_or(Error::ClaimNotFound)?;
 
-        claim.status = new_status;
-        env.storage().instance().set(&DataKey::Claim(claim_id), &claim);
+        Self::set_status(&env, &mut claim, new_status);
+        Self::write_claim(&env, &claim);
 
         env.events().publish((symbol_short!("status_up"),), claim_id);
+
+        Ok(())
     }
 
     /// Increment review count for a claim
-    pub fn increment_review_count(env: Env, caller: Address, claim_id: u64) {
-        caller.require_auth();
+    pub fn increment_review_count(env: Env, caller: Address, claim_id: u64) -> Result<(), Error> {
+        Self::require_review_consensus(&env, &caller)?;
 
-        // Verify caller is the review consensus contract
-        let review_contract: Address = env.storage().instance()
-            .get(&DataKey::ReviewConsensusContract)
-            .expect("Review consensus contract not set");
-
-        if caller != review_contract {
-            panic!("Only review consensus contract can increment review count");
-        }
-
-        let mut claim: Claim = env.storage().instance()
-            .get(&DataKey::Claim(claim_id))
-            .expect("Claim not found");
+        let mut claim = Self::read_claim(&env, claim_id).ok_or(Error::ClaimNotFound)?;
 
         claim.review_count += 1;
-        
+
         // Update status to UnderReview if it was Pending
         if claim.status == ClaimStatus::Pending && claim.review_count > 0 {
-            claim.status = ClaimStatus::UnderReview;
+            Self::set_status(&env, &mut claim, ClaimStatus::UnderReview);
         }
 
-        env.storage().instance().set(&DataKey::Claim(claim_id), &claim);
+        Self::write_claim(&env, &claim);
+
+        Ok(())
     }
 
-    /// Add stake to claim pool
-    pub fn add_to_stake_pool(env: Env, caller: Address, claim_id: u64, amount: i128) {
-        caller.require_auth();
+    /// Transfer a settled claim's stake pool to the review consensus contract
+    pub fn release_stake_pool(env: Env, caller: Address, claim_id: u64) -> Result<i128, Error> {
+        Self::require_review_consensus(&env, &caller)?;
+
+        let mut claim = Self::read_claim(&env, claim_id).ok_or(Error::ClaimNotFound)?;
+        let amount = claim.stake_pool;
+
+        claim.stake_pool = 0;
+        Self::write_claim(&env, &claim);
+
+        if amount > 0 {
+            let token_address: Address = env.storage().instance()
+                .get(&DataKey::Token)
+                .ok_or(Error::NotInitialized)?;
+            token::Client::new(&env, &token_address).transfer(&env.current_contract_address(), &caller, &amount);
+        }
+
+        env.events().publish((symbol_short!("pool_rel"),), claim_id);
+
+        Ok(amount)
+    }
+
+    /// Withdraw a pending claim that has no reviews yet, refunding its sponsors
+    pub fn withdraw_claim(env: Env, submitter: Address, claim_id: u64) -> Result<i128, Error> {
+        submitter.require_auth();
+
+        let mut claim = Self::read_claim(&env, claim_id).ok_or(Error::ClaimNotFound)?;
+        Self::require_unreviewed(&claim, &submitter)?;
+
+        let refunded = Self::refund_sponsors(&env, &mut claim)?;
+        Self::set_status(&env, &mut claim, ClaimStatus::Withdrawn);
+        Self::write_claim(&env, &claim);
+
+        env.events().publish((symbol_short!("withdrawn"), claim_id), refunded);
+
+        Ok(refunded)
+    }
+
+    /// Refund an unresolved claim's stake pool to its sponsors (called by review consensus contract)
+    pub fn refund_stake_pool(env: Env, caller: Address, claim_id: u64) -> Result<i128, Error> {
+        Self::require_review_consensus(&env, &caller)?;
+
+        let mut claim = Self::read_claim(&env, claim_id).ok_or(Error::ClaimNotFound)?;
+
+        let refunded = Self::refund_sponsors(&env, &mut claim)?;
+        Self::write_claim(&env, &claim);
+
+        env.events().publish((symbol_short!("refunded"), claim_id), refunded);
+
+        Ok(refunded)
+    }
+
+    /// Fund a claim's bounty by transferring tokens into its stake pool
+    pub fn add_to_stake_pool(env: Env, sponsor: Address, claim_id: u64, amount: i128) -> Result<(), Error> {
+        sponsor.require_auth();
+
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
 
-        let mut claim: Claim = env.storage().instance()
-            .get(&DataKey::Claim(claim_id))
-            .expect("Claim not found");
+        let mut claim = Self::read_claim(&env, claim_id).ok_or(Error::ClaimNotFound)?;
+
+        // Only claims still open for review can be funded
+        if claim.status != ClaimStatus::Pending && claim.status != ClaimStatus::UnderReview {
+            return Err(Error::ClaimFinalized);
+        }
+
+        let token_address: Address = env.storage().instance()
+            .get(&DataKey::Token)
+            .ok_or(Error::NotInitialized)?;
+        token::Client::new(&env, &token_address).transfer(&sponsor, &env.current_contract_address(), &amount);
 
         claim.stake_pool += amount;
-        env.storage().instance().set(&DataKey::Claim(claim_id), &claim);
+        Self::write_claim(&env, &claim);
+        Self::record_contribution(&env, claim_id, &sponsor, amount);
+
+        env.events().publish((symbol_short!("bounty"), claim_id), (sponsor, amount));
+
+        Ok(())
+    }
+
+    /// Get the total a sponsor has contributed to a claim's stake pool
+    pub fn get_contribution(env: Env, claim_id: u64, sponsor: Address) -> i128 {
+        env.storage().persistent().get(&DataKey::Contribution(claim_id, sponsor)).unwrap_or(0)
+    }
+
+    /// Get every address that has contributed to a claim's stake pool
+    pub fn get_sponsors(env: Env, claim_id: u64) -> Vec<Address> {
+        env.storage().persistent().get(&DataKey::ClaimSponsors(claim_id)).unwrap_or(Vec::new(&env))
     }
 
     /// Get all claims (paginated)
@@ -209,13 +557,402 @@ impl ClaimRegistry {
         };
 
         for i in start..end {
-            if let Some(claim) = env.storage().instance().get(&DataKey::Claim(i + 1)) {
+            if let Some(claim) = Self::read_claim(&env, i + 1) {
                 claims.push_back(claim);
             }
         }
 
         claims
     }
+
+    /// Get the claims filed by a submitter (paginated; removals reorder the index)
+    pub fn get_claims_by_submitter(env: Env, submitter: Address, start: u32, limit: u32) -> Vec<Claim> {
+        Self::read_index_page(&env, &ClaimIndex::Submitter(submitter), start, limit)
+    }
+
+    /// Get the claims in a category (paginated; removals reorder the index)
+    pub fn get_claims_by_category(env: Env, category: String, start: u32, limit: u32) -> Vec<Claim> {
+        Self::read_index_page(&env, &ClaimIndex::Category(category), start, limit)
+    }
+
+    /// Get the claims currently in a status (paginated; removals reorder the index)
+    pub fn get_claims_by_status(env: Env, status: ClaimStatus, start: u32, limit: u32) -> Vec<Claim> {
+        Self::read_index_page(&env, &ClaimIndex::Status(status), start, limit)
+    }
+
+    /// Get the number of claims in an index
+    pub fn get_index_size(env: Env, index: ClaimIndex) -> u32 {
+        env.storage().persistent().get(&DataKey::IndexSize(index)).unwrap_or(0)
+    }
+
+    /// Extend the TTL of claims at risk of archival
+    pub fn bump_ttl(env: Env, admin: Address, claim_ids: Vec<u64>) -> Result<(), Error> {
+        Self::require_admin(&env, &admin)?;
+
+        for claim_id in claim_ids.iter() {
+            let key = DataKey::Claim(claim_id);
+            let claim: Option<Claim> = env.storage().persistent().get(&key);
+            if let Some(claim) = claim {
+                env.storage().persistent().extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+
+                for index in [
+                    ClaimIndex::Submitter(claim.submitter.clone()),
+                    ClaimIndex::Category(claim.category.clone()),
+                    ClaimIndex::Status(claim.status.clone()),
+                ] {
+                    let position: Option<u32> = env.storage().persistent()
+                        .get(&DataKey::IndexPosition(index.clone(), claim_id));
+                    if let Some(position) = position {
+                        for key in [
+                            DataKey::IndexSize(index.clone()),
+                            DataKey::IndexEntry(index.clone(), position),
+                            DataKey::IndexPosition(index, claim_id),
+                        ] {
+                            env.storage().persistent().extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+                        }
+                    }
+                }
+
+                let key = DataKey::ClaimHash(Self::content_hash(&env, &claim.text));
+                if env.storage().persistent().has(&key) {
+                    env.storage().persistent().extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+                }
+
+                for version in 1..=claim.version {
+                    let key = DataKey::ClaimVersion(claim_id, version);
+                    if env.storage().persistent().has(&key) {
+                        env.storage().persistent().extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+                    }
+                }
+            }
+
+            let sponsors_key = DataKey::ClaimSponsors(claim_id);
+            let sponsors: Option<Vec<Address>> = env.storage().persistent().get(&sponsors_key);
+            if let Some(sponsors) = sponsors {
+                env.storage().persistent().extend_ttl(&sponsors_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+                for sponsor in sponsors.iter() {
+                    let key = DataKey::Contribution(claim_id, sponsor);
+                    if env.storage().persistent().has(&key) {
+                        env.storage().persistent().extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+                    }
+                }
+            }
+        }
+        Self::extend_instance_ttl(&env);
+
+        Ok(())
+    }
+
+    // Helper functions
+
+    fn read_claim(env: &Env, claim_id: u64) -> Option<Claim> {
+        let key = DataKey::Claim(claim_id);
+        let claim: Option<Claim> = env.storage().persistent().get(&key);
+        if claim.is_some() {
+            env.storage().persistent().extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+        }
+        claim
+    }
+
+    fn write_claim(env: &Env, claim: &Claim) {
+        let key = DataKey::Claim(claim.id);
+        env.storage().persistent().set(&key, claim);
+        env.storage().persistent().extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+        Self::extend_instance_ttl(env);
+    }
+
+    /// Return the stake pool to sponsors in proportion to their contributions, rounding dust to the submitter
+    fn refund_sponsors(env: &Env, claim: &mut Claim) -> Result<i128, Error> {
+        let pool = claim.stake_pool;
+        if pool <= 0 {
+            return Ok(0);
+        }
+
+        let token_address: Address = env.storage().instance()
+            .get(&DataKey::Token)
+            .ok_or(Error::NotInitialized)?;
+        let token_client = token::Client::new(env, &token_address);
+        let this = env.current_contract_address();
+
+        let sponsors: Vec<Address> = env.storage().persistent()
+            .get(&DataKey::ClaimSponsors(claim.id))
+            .unwrap_or(Vec::new(env));
+
+        let mut total_contributed: i128 = 0;
+        for sponsor in sponsors.iter() {
+            total_contributed += Self::get_contribution(env.clone(), claim.id, sponsor);
+        }
+
+        let mut refunded: i128 = 0;
+        if total_contributed > 0 {
+            for sponsor in sponsors.iter() {
+                let share = pool * Self::get_contribution(env.clone(), claim.id, sponsor.clone()) / total_contributed;
+                if share > 0 {
+                    token_client.transfer(&this, &sponsor, &share);
+                    refunded += share;
+                }
+            }
+        }
+
+        if pool > refunded {
+            token_client.transfer(&this, &claim.submitter, &(pool - refunded));
+        }
+
+        claim.stake_pool = 0;
+
+        Ok(pool)
+    }
+
+    fn record_contribution(env: &Env, claim_id: u64, sponsor: &Address, amount: i128) {
+        let key = DataKey::Contribution(claim_id, sponsor.clone());
+        let previous: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+
+        if previous == 0 {
+            let sponsors_key = DataKey::ClaimSponsors(claim_id);
+            let mut sponsors: Vec<Address> = env.storage().persistent().get(&sponsors_key).unwrap_or(Vec::new(env));
+            sponsors.push_back(sponsor.clone());
+            env.storage().persistent().set(&sponsors_key, &sponsors);
+            env.storage().persistent().extend_ttl(&sponsors_key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+        }
+
+        env.storage().persistent().set(&key, &(previous + amount));
+        env.storage().persistent().extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+    }
+
+    fn write_version(env: &Env, claim: &Claim) {
+        let snapshot = ClaimVersion {
+            claim_id: claim.id,
+            version: claim.version,
+            text: claim.text.clone(),
+            category: claim.category.clone(),
+            sources: claim.sources.clone(),
+            timestamp: env.ledger().timestamp(),
+        };
+
+        let key = DataKey::ClaimVersion(claim.id, claim.version);
+        env.storage().persistent().set(&key, &snapshot);
+        env.storage().persistent().extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+    }
+
+    fn read_category(env: &Env, slug: &String) -> Option<Category> {
+        let key = DataKey::Category(slug.clone());
+        let category: Option<Category> = env.storage().persistent().get(&key);
+        if category.is_some() {
+            env.storage().persistent().extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+        }
+        category
+    }
+
+    fn write_category(env: &Env, category: &Category) -> Result<(), Error> {
+        // A category without a quorum could never be finalized
+        if category.claim_fee < 0 || category.min_reviews == 0 {
+            return Err(Error::InvalidCategoryParams);
+        }
+
+        let key = DataKey::Category(category.slug.clone());
+        env.storage().persistent().set(&key, category);
+        env.storage().persistent().extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+        Self::extend_instance_ttl(env);
+
+        Ok(())
+    }
+
+    fn require_active_category(env: &Env, slug: &String) -> Result<Category, Error> {
+        let category = Self::read_category(env, slug).ok_or(Error::CategoryNotFound)?;
+        if !category.active {
+            return Err(Error::CategoryRetired);
+        }
+
+        Ok(category)
+    }
+
+    /// Slugs are non-empty lowercase ASCII letters, digits and hyphens
+    fn validate_slug(slug: &String) -> Result<(), Error> {
+        let len = slug.len() as usize;
+        if len == 0 || len > MAX_SLUG_LENGTH as usize {
+            return Err(Error::InvalidSlug);
+        }
+
+        let mut buffer = [0u8; MAX_SLUG_LENGTH as usize];
+        slug.copy_into_slice(&mut buffer[..len]);
+
+        if !buffer[..len].iter().all(|byte| byte.is_ascii_lowercase() || byte.is_ascii_digit() || *byte == b'-') {
+            return Err(Error::InvalidSlug);
+        }
+
+        Ok(())
+    }
+
+    /// Move a claim between status indexes; the caller writes the claim
+    fn set_status(env: &Env, claim: &mut Claim, new_status: ClaimStatus) {
+        if claim.status == new_status {
+            return;
+        }
+
+        Self::index_remove(env, &ClaimIndex::Status(claim.status.clone()), claim.id);
+        Self::index_add(env, &ClaimIndex::Status(new_status.clone()), claim.id);
+        claim.status = new_status;
+    }
+
+    // Indexes store one entry per claim, so adding and removing touch a fixed number of entries
+    fn index_add(env: &Env, index: &ClaimIndex, claim_id: u64) {
+        let size = Self::get_index_size(env.clone(), index.clone());
+
+        Self::write_index_key(env, &DataKey::IndexEntry(index.clone(), size), &claim_id);
+        Self::write_index_key(env, &DataKey::IndexPosition(index.clone(), claim_id), &size);
+        Self::write_index_key(env, &DataKey::IndexSize(index.clone()), &(size + 1));
+    }
+
+    // The index's last claim moves into the removed claim's position
+    fn index_remove(env: &Env, index: &ClaimIndex, claim_id: u64) {
+        let position_key = DataKey::IndexPosition(index.clone(), claim_id);
+        let position: u32 = match env.storage().persistent().get(&position_key) {
+            Some(position) => position,
+            None => return,
+        };
+
+        let last = Self::get_index_size(env.clone(), index.clone()) - 1;
+        if position != last {
+            let last_id: u64 = env.storage().persistent().get(&DataKey::IndexEntry(index.clone(), last)).unwrap();
+            Self::write_index_key(env, &DataKey::IndexEntry(index.clone(), position), &last_id);
+            Self::write_index_key(env, &DataKey::IndexPosition(index.clone(), last_id), &position);
+        }
+
+        env.storage().persistent().remove(&DataKey::IndexEntry(index.clone(), last));
+        env.storage().persistent().remove(&position_key);
+        Self::write_index_key(env, &DataKey::IndexSize(index.clone()), &last);
+    }
+
+    fn write_index_key<V: IntoVal<Env, Val>>(env: &Env, key: &DataKey, value: &V) {
+        env.storage().persistent().set(key, value);
+        env.storage().persistent().extend_ttl(key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+    }
+
+    fn read_index_page(env: &Env, index: &ClaimIndex, start: u32, limit: u32) -> Vec<Claim> {
+        let mut claims = Vec::new(env);
+
+        let end = Self::get_index_size(env.clone(), index.clone()).min(start.saturating_add(limit));
+        for position in start..end {
+            let claim_id: Option<u64> = env.storage().persistent().get(&DataKey::IndexEntry(index.clone(), position));
+            if let Some(claim) = claim_id.and_then(|claim_id| Self::read_claim(env, claim_id)) {
+                claims.push_back(claim);
+            }
+        }
+
+        claims
+    }
+
+    /// sha256 of the text lowercased (ASCII) with whitespace runs collapsed and trimmed
+    fn content_hash(env: &Env, text: &String) -> BytesN<32> {
+        let len = text.len();
+        let mut normalized = Bytes::new(env);
+        let mut chunk = [0u8; TEXT_CHUNK_SIZE];
+        // A whitespace run carried over from the previous chunk can add one byte
+        let mut output = [0u8; TEXT_CHUNK_SIZE + 1];
+        let mut pending_space = false;
+
+        // Texts of any length are normalized a chunk at a time
+        let mut position = 0;
+        while position < len {
+            let chunk_len = (len - position).min(TEXT_CHUNK_SIZE as u32) as usize;
+            env.string_copy_to_slice(text.to_object(), Val::from_u32(position), &mut chunk[..chunk_len])
+                .unwrap();
+
+            let mut output_len = 0;
+            for &byte in &chunk[..chunk_len] {
+                if byte.is_ascii_whitespace() {
+                    pending_space = output_len > 0 || !normalized.is_empty();
+                    continue;
+                }
+                if pending_space {
+                    output[output_len] = b' ';
+                    output_len += 1;
+                    pending_space = false;
+                }
+                output[output_len] = byte.to_ascii_lowercase();
+                output_len += 1;
+            }
+
+            normalized.extend_from_slice(&output[..output_len]);
+            position += chunk_len as u32;
+        }
+
+        env.crypto().sha256(&normalized).into()
+    }
+
+    /// Texts that normalize to nothing (empty or only whitespace) are not claims
+    fn require_text(env: &Env, content_hash: &BytesN<32>) -> Result<(), Error> {
+        let blank: BytesN<32> = env.crypto().sha256(&Bytes::new(env)).into();
+        if *content_hash == blank {
+            return Err(Error::EmptyClaim);
+        }
+
+        Ok(())
+    }
+
+    /// A claim keeps its text's hash while it is open or carries a verdict, so resubmissions link to it;
+    /// claims that ended without one (withdrawn, expired or inconclusive) free the text for a fresh claim
+    fn find_duplicate(env: &Env, content_hash: &BytesN<32>) -> Option<u64> {
+        let claim_id: u64 = env.storage().persistent().get(&DataKey::ClaimHash(content_hash.clone()))?;
+        let claim = Self::read_claim(env, claim_id)?;
+
+        match claim.status {
+            ClaimStatus::Withdrawn | ClaimStatus::Expired | ClaimStatus::Inconclusive => None,
+            _ => Some(claim_id),
+        }
+    }
+
+    fn write_content_hash(env: &Env, content_hash: &BytesN<32>, claim_id: u64) {
+        let key = DataKey::ClaimHash(content_hash.clone());
+        env.storage().persistent().set(&key, &claim_id);
+        env.storage().persistent().extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+    }
+
+    /// Only the submitter may change a claim, and only before any reviewer has committed stake
+    fn require_unreviewed(claim: &Claim, submitter: &Address) -> Result<(), Error> {
+        if claim.submitter != *submitter {
+            return Err(Error::NotAuthorized);
+        }
+
+        match claim.status {
+            ClaimStatus::Pending if claim.review_count == 0 => Ok(()),
+            ClaimStatus::Pending | ClaimStatus::UnderReview => Err(Error::ClaimUnderReview),
+            _ => Err(Error::ClaimFinalized),
+        }
+    }
+
+    fn extend_instance_ttl(env: &Env) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+    }
+
+    fn require_admin(env: &Env, admin: &Address) -> Result<(), Error> {
+        admin.require_auth();
+
+        let stored_admin: Address = env.storage().instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+
+        if *admin != stored_admin {
+            return Err(Error::NotAuthorized);
+        }
+
+        Ok(())
+    }
+
+    fn require_review_consensus(env: &Env, caller: &Address) -> Result<(), Error> {
+        caller.require_auth();
+
+        // Verify caller is the review consensus contract
+        let review_contract: Address = env.storage().instance()
+            .get(&DataKey::ReviewConsensusContract)
+            .ok_or(Error::ReviewContractNotSet)?;
+
+        if *caller != review_contract {
+            return Err(Error::NotAuthorized);
+        }
+
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -1,7 +1,25 @@
 #![cfg(test)]
 
 use super::*;
-use soroban_sdk::{testutils::Address as _, Address, Env, String, Vec};
+use soroban_sdk::{testutils::{storage::Persistent as _, Address as _, Ledger}, token::{StellarAssetClient, TokenClient}, Address, Env, String, Vec};
+
+// Submission fee of the categories created by the tests
+const CLAIM_FEE: i128 = 5_000_000;
+
+fn create_token<'a>(env: &Env, admin: &Address) -> (Address, StellarAssetClient<'a>, TokenClient<'a>) {
+    let token_address = env.register_stellar_asset_contract_v2(admin.clone()).address();
+    (
+        token_address.clone(),
+        StellarAssetClient::new(env, &token_address),
+        TokenClient::new(env, &token_address),
+    )
+}
+
+fn create_category(env: &Env, client: &ClaimRegistryClient, admin: &Address, slug: &str) {
+    let slug = String::from_str(env, slug);
+    let description = String::from_str(env, "Claims reviewed by the tests");
+    client.create_category(admin, &slug, &slug, &description, &CLAIM_FEE, &3, &ExpertLevel::General);
+}
 
 #[test]
 fn test_initialize() {
@@ -10,31 +28,36 @@ fn test_initialize() {
     let client = ClaimRegistryClient::new(&env, &contract_id);
 
     let admin = Address::generate(&env);
-    client.initialize(&admin);
+    let (token_address, _, _) = create_token(&env, &admin);
+    client.initialize(&admin, &token_address);
 
     assert_eq!(client.get_claim_count(), 0);
+    assert_eq!(client.get_token(), token_address);
 }
 
 #[test]
 fn test_submit_claim() {
     let env = Env::default();
     env.mock_all_auths();
-    
+
     let contract_id = env.register_contract(None, ClaimRegistry);
     let client = ClaimRegistryClient::new(&env, &contract_id);
 
     let admin = Address::generate(&env);
     let submitter = Address::generate(&env);
-    
-    client.initialize(&admin);
+    let (token_address, token_admin, token) = create_token(&env, &admin);
+    token_admin.mint(&submitter, &10_000_000);
+
+    client.initialize(&admin, &token_address);
+    create_category(&env, &client, &admin, "science");
 
     let text = String::from_str(&env, "The Earth is round");
-    let category = String::from_str(&env, "Science");
+    let category = String::from_str(&env, "science");
     let mut sources = Vec::new(&env);
     sources.push_back(String::from_str(&env, "https://nasa.gov"));
 
     let claim_id = client.submit_claim(&submitter, &text, &category, &sources);
-    
+
     assert_eq!(claim_id, 1);
     assert_eq!(client.get_claim_count(), 1);
 
@@ -42,4 +65,520 @@ fn test_submit_claim() {
     assert_eq!(claim.id, 1);
     assert_eq!(claim.status, ClaimStatus::Pending);
     assert_eq!(claim.review_count, 0);
+    assert_eq!(claim.stake_pool, CLAIM_FEE);
+
+    // Fee moved from submitter to contract custody
+    assert_eq!(token.balance(&submitter), 10_000_000 - CLAIM_FEE);
+    assert_eq!(token.balance(&contract_id), CLAIM_FEE);
+}
+
+#[test]
+#[should_panic]
+fn test_submit_claim_insufficient_balance() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ClaimRegistry);
+    let client = ClaimRegistryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let submitter = Address::generate(&env);
+    let (token_address, _, _) = create_token(&env, &admin);
+
+    client.initialize(&admin, &token_address);
+    create_category(&env, &client, &admin, "science");
+
+    let text = String::from_str(&env, "The Earth is round");
+    let category = String::from_str(&env, "science");
+    client.submit_claim(&submitter, &text, &category, &Vec::new(&env));
+}
+
+#[test]
+fn test_categories() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ClaimRegistry);
+    let client = ClaimRegistryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let outsider = Address::generate(&env);
+    let submitter = Address::generate(&env);
+    let (token_address, token_admin, token) = create_token(&env, &admin);
+    token_admin.mint(&submitter, &100_000_000);
+
+    client.initialize(&admin, &token_address);
+
+    let name = String::from_str(&env, "Health");
+    let description = String::from_str(&env, "Medicine and public health");
+    let health = String::from_str(&env, "health");
+    let create = |slug: &String, fee: i128, min_reviews: u32| {
+        client.try_create_category(&admin, slug, &name, &description, &fee, &min_reviews, &ExpertLevel::Specialized)
+    };
+
+    assert_eq!(
+        client.try_create_category(&outsider, &health, &name, &description, &0, &3, &ExpertLevel::General),
+        Err(Ok(Error::NotAuthorized))
+    );
+    assert_eq!(create(&String::from_str(&env, "Health"), 0, 3), Err(Ok(Error::InvalidSlug)));
+    assert_eq!(create(&String::from_str(&env, "public health"), 0, 3), Err(Ok(Error::InvalidSlug)));
+    assert_eq!(create(&String::from_str(&env, ""), 0, 3), Err(Ok(Error::InvalidSlug)));
+    assert_eq!(create(&health, -1, 3), Err(Ok(Error::InvalidCategoryParams)));
+    assert_eq!(create(&health, 0, 0), Err(Ok(Error::InvalidCategoryParams)));
+
+    assert_eq!(create(&health, 20_000_000, 5), Ok(Ok(())));
+    assert_eq!(create(&health, 20_000_000, 5), Err(Ok(Error::CategoryExists)));
+    create_category(&env, &client, &admin, "public-health");
+
+    let category = client.get_category(&health).unwrap();
+    assert_eq!(category.name, name);
+    assert_eq!(category.min_reviews, 5);
+    assert_eq!(category.min_expert_level, ExpertLevel::Specialized);
+    assert!(category.active);
+    assert_eq!(client.get_categories().len(), 2);
+
+    // Claims pay their category's fee and must use a registered slug
+    let text = String::from_str(&env, "Vitamin C cures colds");
+    assert_eq!(
+        client.try_submit_claim(&submitter, &text, &String::from_str(&env, "Health"), &Vec::new(&env)),
+        Err(Ok(Error::CategoryNotFound))
+    );
+    let claim_id = client.submit_claim(&submitter, &text, &health, &Vec::new(&env));
+    assert_eq!(client.get_claim(&claim_id).unwrap().stake_pool, 20_000_000);
+    assert_eq!(token.balance(&submitter), 80_000_000);
+
+    client.update_category(&admin, &health, &description, &10_000_000, &3, &ExpertLevel::General);
+    let claim_id = client.submit_claim(&submitter, &String::from_str(&env, "Sugar causes hyperactivity"), &health, &Vec::new(&env));
+    assert_eq!(client.get_claim(&claim_id).unwrap().stake_pool, 10_000_000);
+
+    // Retired categories take no new claims
+    client.retire_category(&admin, &health);
+    assert!(!client.get_category(&health).unwrap().active);
+    assert_eq!(
+        client.try_submit_claim(&submitter, &String::from_str(&env, "Coffee stunts growth"), &health, &Vec::new(&env)),
+        Err(Ok(Error::CategoryRetired))
+    );
+
+    assert_eq!(client.try_retire_category(&admin, &String::from_str(&env, "sports")), Err(Ok(Error::CategoryNotFound)));
+}
+
+#[test]
+fn test_add_to_stake_pool() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ClaimRegistry);
+    let client = ClaimRegistryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let submitter = Address::generate(&env);
+    let sponsor = Address::generate(&env);
+    let review_contract = Address::generate(&env);
+    let (token_address, token_admin, token) = create_token(&env, &admin);
+    token_admin.mint(&submitter, &CLAIM_FEE);
+    token_admin.mint(&sponsor, &100_000_000);
+
+    client.initialize(&admin, &token_address);
+    create_category(&env, &client, &admin, "science");
+    client.set_review_consensus(&admin, &review_contract);
+
+    let text = String::from_str(&env, "The Earth is round");
+    let category = String::from_str(&env, "science");
+    let claim_id = client.submit_claim(&submitter, &text, &category, &Vec::new(&env));
+
+    assert_eq!(client.try_add_to_stake_pool(&sponsor, &claim_id, &0), Err(Ok(Error::InvalidAmount)));
+    assert_eq!(client.try_add_to_stake_pool(&sponsor, &claim_id, &-1), Err(Ok(Error::InvalidAmount)));
+    assert_eq!(client.try_add_to_stake_pool(&sponsor, &99, &10_000_000), Err(Ok(Error::ClaimNotFound)));
+
+    // Contributions are transferred into custody and tracked per sponsor
+    client.add_to_stake_pool(&sponsor, &claim_id, &30_000_000);
+    client.add_to_stake_pool(&sponsor, &claim_id, &20_000_000);
+
+    assert_eq!(client.get_claim(&claim_id).unwrap().stake_pool, CLAIM_FEE + 50_000_000);
+    assert_eq!(token.balance(&sponsor), 50_000_000);
+    assert_eq!(token.balance(&contract_id), CLAIM_FEE + 50_000_000);
+    assert_eq!(client.get_contribution(&claim_id, &sponsor), 50_000_000);
+    assert_eq!(client.get_contribution(&claim_id, &submitter), CLAIM_FEE);
+
+    let sponsors = client.get_sponsors(&claim_id);
+    assert_eq!(sponsors.len(), 2);
+    assert_eq!(sponsors.get(0).unwrap(), submitter);
+    assert_eq!(sponsors.get(1).unwrap(), sponsor);
+
+    // Claims with a verdict no longer accept bounties
+    client.update_claim_status(&review_contract, &claim_id, &ClaimStatus::True);
+    assert_eq!(client.try_add_to_stake_pool(&sponsor, &claim_id, &10_000_000), Err(Ok(Error::ClaimFinalized)));
+}
+
+#[test]
+fn test_withdraw_claim() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ClaimRegistry);
+    let client = ClaimRegistryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let submitter = Address::generate(&env);
+    let sponsor = Address::generate(&env);
+    let review_contract = Address::generate(&env);
+    let (token_address, token_admin, token) = create_token(&env, &admin);
+    token_admin.mint(&submitter, &(2 * CLAIM_FEE));
+    token_admin.mint(&sponsor, &30_000_000);
+
+    client.initialize(&admin, &token_address);
+    create_category(&env, &client, &admin, "science");
+    client.set_review_consensus(&admin, &review_contract);
+
+    let text = String::from_str(&env, "The Earth is round");
+    let category = String::from_str(&env, "science");
+    let claim_id = client.submit_claim(&submitter, &text, &category, &Vec::new(&env));
+    client.add_to_stake_pool(&sponsor, &claim_id, &30_000_000);
+
+    // Only the submitter can withdraw
+    assert_eq!(client.try_withdraw_claim(&sponsor, &claim_id), Err(Ok(Error::NotAuthorized)));
+
+    // Every sponsor gets their contribution back
+    assert_eq!(client.withdraw_claim(&submitter, &claim_id), CLAIM_FEE + 30_000_000);
+    assert_eq!(token.balance(&submitter), 2 * CLAIM_FEE);
+    assert_eq!(token.balance(&sponsor), 30_000_000);
+    assert_eq!(token.balance(&contract_id), 0);
+
+    let claim = client.get_claim(&claim_id).unwrap();
+    assert_eq!(claim.status, ClaimStatus::Withdrawn);
+    assert_eq!(claim.stake_pool, 0);
+    assert_eq!(client.try_withdraw_claim(&submitter, &claim_id), Err(Ok(Error::ClaimFinalized)));
+    assert_eq!(client.try_add_to_stake_pool(&sponsor, &claim_id, &10_000_000), Err(Ok(Error::ClaimFinalized)));
+
+    // Claims with reviews can no longer be withdrawn
+    let claim_id = client.submit_claim(&submitter, &text, &category, &Vec::new(&env));
+    client.increment_review_count(&review_contract, &claim_id);
+    assert_eq!(client.try_withdraw_claim(&submitter, &claim_id), Err(Ok(Error::ClaimUnderReview)));
+
+    // The review contract refunds unresolved claims pro rata
+    assert_eq!(client.refund_stake_pool(&review_contract, &claim_id), CLAIM_FEE);
+    assert_eq!(token.balance(&submitter), 2 * CLAIM_FEE);
+    assert_eq!(client.get_claim(&claim_id).unwrap().stake_pool, 0);
+}
+
+#[test]
+fn test_amend_claim() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ClaimRegistry);
+    let client = ClaimRegistryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let submitter = Address::generate(&env);
+    let outsider = Address::generate(&env);
+    let review_contract = Address::generate(&env);
+    let (token_address, token_admin, _) = create_token(&env, &admin);
+    token_admin.mint(&submitter, &CLAIM_FEE);
+
+    client.initialize(&admin, &token_address);
+    create_category(&env, &client, &admin, "science");
+    client.set_review_consensus(&admin, &review_contract);
+
+    let category = String::from_str(&env, "science");
+    let claim_id = client.submit_claim(&submitter, &String::from_str(&env, "The Earth is flta"), &category, &Vec::new(&env));
+    assert_eq!(client.get_claim(&claim_id).unwrap().version, 1);
+
+    let text = String::from_str(&env, "The Earth is flat");
+    assert_eq!(
+        client.try_amend_claim(&outsider, &claim_id, &text, &Vec::new(&env)),
+        Err(Ok(Error::NotAuthorized))
+    );
+
+    // Amendments update the claim and keep every earlier version
+    let mut sources = Vec::new(&env);
+    sources.push_back(String::from_str(&env, "https://nasa.gov"));
+    env.ledger().set_timestamp(1_000);
+    assert_eq!(client.amend_claim(&submitter, &claim_id, &text, &sources), 2);
+
+    let claim = client.get_claim(&claim_id).unwrap();
+    assert_eq!(claim.version, 2);
+    assert_eq!(claim.text, text);
+    assert_eq!(claim.sources, sources);
+
+    let history = client.get_claim_history(&claim_id);
+    assert_eq!(history.len(), 2);
+    assert_eq!(history.get(0).unwrap().text, String::from_str(&env, "The Earth is flta"));
+    assert_eq!(history.get(0).unwrap().sources.len(), 0);
+    assert_eq!(history.get(1).unwrap().text, text);
+    assert_eq!(history.get(1).unwrap().timestamp, 1_000);
+    assert_eq!(client.get_claim_version(&claim_id, &2).unwrap().sources, sources);
+    assert!(client.get_claim_version(&claim_id, &3).is_none());
+
+    // Claims are frozen once reviewing starts
+    client.increment_review_count(&review_contract, &claim_id);
+    assert_eq!(
+        client.try_amend_claim(&submitter, &claim_id, &text, &sources),
+        Err(Ok(Error::ClaimUnderReview))
+    );
+    assert_eq!(client.get_claim_history(&99).len(), 0);
+}
+
+#[test]
+fn test_duplicate_claims() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ClaimRegistry);
+    let client = ClaimRegistryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let submitter = Address::generate(&env);
+    let copycat = Address::generate(&env);
+    let (token_address, token_admin, token) = create_token(&env, &admin);
+    token_admin.mint(&submitter, &(3 * CLAIM_FEE));
+    token_admin.mint(&copycat, &CLAIM_FEE);
+
+    client.initialize(&admin, &token_address);
+    create_category(&env, &client, &admin, "science");
+
+    let category = String::from_str(&env, "science");
+    let claim_id = client.submit_claim(&submitter, &String::from_str(&env, "The Earth is round"), &category, &Vec::new(&env));
+
+    // Case and whitespace are normalized away
+    let variant = String::from_str(&env, "  the EARTH is\n round ");
+    let content_hash = client.compute_content_hash(&variant);
+    assert_eq!(content_hash, client.compute_content_hash(&String::from_str(&env, "The Earth is round")));
+    assert_ne!(content_hash, client.compute_content_hash(&String::from_str(&env, "The Earth is flat")));
+    assert_eq!(client.find_claim_by_hash(&content_hash), Some(claim_id));
+
+    // Duplicates link to the existing claim without charging a fee
+    assert_eq!(client.submit_claim(&copycat, &variant, &category, &Vec::new(&env)), claim_id);
+    assert_eq!(client.get_claim_count(), 1);
+    assert_eq!(token.balance(&copycat), CLAIM_FEE);
+
+    // Amendments cannot collide with another claim, and move the index with the text
+    let other_id = client.submit_claim(&submitter, &String::from_str(&env, "The Moon is cheese"), &category, &Vec::new(&env));
+    assert_eq!(
+        client.try_amend_claim(&submitter, &other_id, &variant, &Vec::new(&env)),
+        Err(Ok(Error::DuplicateClaim))
+    );
+    let amended = String::from_str(&env, "The Moon is rock");
+    client.amend_claim(&submitter, &other_id, &amended, &Vec::new(&env));
+    assert_eq!(client.find_claim_by_hash(&client.compute_content_hash(&amended)), Some(other_id));
+    assert_eq!(client.find_claim_by_hash(&client.compute_content_hash(&String::from_str(&env, "The Moon is cheese"))), None);
+
+    // Withdrawn claims can be submitted afresh
+    client.withdraw_claim(&submitter, &claim_id);
+    assert_eq!(client.find_claim_by_hash(&content_hash), None);
+    let resubmitted = client.submit_claim(&submitter, &variant, &category, &Vec::new(&env));
+    assert_eq!(resubmitted, 3);
+    assert_eq!(client.find_claim_by_hash(&content_hash), Some(resubmitted));
+
+    // Long texts are normalized across the chunks they are read in
+    let mut long_text = [b'A'; 4 * TEXT_CHUNK_SIZE];
+    long_text[TEXT_CHUNK_SIZE - 2..TEXT_CHUNK_SIZE + 2].copy_from_slice(b" \t\n ");
+    let mut normalized = [b'a'; 4 * TEXT_CHUNK_SIZE - 3];
+    normalized[TEXT_CHUNK_SIZE - 2] = b' ';
+
+    let long_text = String::from_bytes(&env, &long_text);
+    assert_eq!(client.compute_content_hash(&long_text), client.compute_content_hash(&String::from_bytes(&env, &normalized)));
+    let long_id = client.submit_claim(&submitter, &long_text, &category, &Vec::new(&env));
+    assert_eq!(client.get_claim(&long_id).unwrap().text, long_text);
+}
+
+#[test]
+fn test_duplicate_check_follows_validation_and_status() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ClaimRegistry);
+    let client = ClaimRegistryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let submitter = Address::generate(&env);
+    let review_contract = Address::generate(&env);
+    let (token_address, token_admin, token) = create_token(&env, &admin);
+    token_admin.mint(&submitter, &(3 * CLAIM_FEE));
+
+    client.initialize(&admin, &token_address);
+    create_category(&env, &client, &admin, "science");
+    create_category(&env, &client, &admin, "health");
+    client.retire_category(&admin, &String::from_str(&env, "health"));
+    client.set_review_consensus(&admin, &review_contract);
+
+    let science = String::from_str(&env, "science");
+    let text = String::from_str(&env, "The Earth is round");
+    let claim_id = client.submit_claim(&submitter, &text, &science, &Vec::new(&env));
+
+    // Invalid submissions are rejected even when their text matches an existing claim
+    assert_eq!(
+        client.try_submit_claim(&submitter, &text, &String::from_str(&env, "sports"), &Vec::new(&env)),
+        Err(Ok(Error::CategoryNotFound))
+    );
+    assert_eq!(
+        client.try_submit_claim(&submitter, &text, &String::from_str(&env, "health"), &Vec::new(&env)),
+        Err(Ok(Error::CategoryRetired))
+    );
+    let blank = String::from_str(&env, " \t\n ");
+    assert_eq!(client.try_submit_claim(&submitter, &blank, &science, &Vec::new(&env)), Err(Ok(Error::EmptyClaim)));
+    assert_eq!(client.try_amend_claim(&submitter, &claim_id, &blank, &Vec::new(&env)), Err(Ok(Error::EmptyClaim)));
+
+    // A verdict keeps the text linked to its claim
+    client.update_claim_status(&review_contract, &claim_id, &ClaimStatus::True);
+    assert_eq!(client.submit_claim(&submitter, &text, &science, &Vec::new(&env)), claim_id);
+
+    // An inconclusive claim frees its text for a fresh claim, which pays its own fee
+    let unresolved = String::from_str(&env, "Coffee stunts growth");
+    let unresolved_id = client.submit_claim(&submitter, &unresolved, &science, &Vec::new(&env));
+    client.update_claim_status(&review_contract, &unresolved_id, &ClaimStatus::Inconclusive);
+    assert_eq!(client.find_claim_by_hash(&client.compute_content_hash(&unresolved)), None);
+
+    let fresh_id = client.submit_claim(&submitter, &unresolved, &science, &Vec::new(&env));
+    assert_ne!(fresh_id, unresolved_id);
+    assert_eq!(client.find_claim_by_hash(&client.compute_content_hash(&unresolved)), Some(fresh_id));
+    assert_eq!(token.balance(&submitter), 0);
+}
+
+#[test]
+fn test_claim_indexes() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ClaimRegistry);
+    let client = ClaimRegistryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let review_contract = Address::generate(&env);
+    let (token_address, token_admin, _) = create_token(&env, &admin);
+    token_admin.mint(&alice, &(2 * CLAIM_FEE));
+    token_admin.mint(&bob, &CLAIM_FEE);
+
+    client.initialize(&admin, &token_address);
+    create_category(&env, &client, &admin, "science");
+    create_category(&env, &client, &admin, "health");
+    client.set_review_consensus(&admin, &review_contract);
+
+    let science = String::from_str(&env, "science");
+    let health = String::from_str(&env, "health");
+    let first = client.submit_claim(&alice, &String::from_str(&env, "The Earth is round"), &science, &Vec::new(&env));
+    let second = client.submit_claim(&bob, &String::from_str(&env, "Vitamin C cures colds"), &health, &Vec::new(&env));
+    let third = client.submit_claim(&alice, &String::from_str(&env, "Water boils at 100C"), &science, &Vec::new(&env));
+
+    let ids = |claims: Vec<Claim>| -> Vec<u64> {
+        let mut ids = Vec::new(&env);
+        for claim in claims.iter() {
+            ids.push_back(claim.id);
+        }
+        ids
+    };
+
+    assert_eq!(ids(client.get_claims_by_submitter(&alice, &0, &10)), Vec::from_array(&env, [first, third]));
+    assert_eq!(ids(client.get_claims_by_submitter(&alice, &1, &10)), Vec::from_array(&env, [third]));
+    assert_eq!(ids(client.get_claims_by_submitter(&alice, &0, &1)), Vec::from_array(&env, [first]));
+    assert_eq!(ids(client.get_claims_by_category(&health, &0, &10)), Vec::from_array(&env, [second]));
+    assert_eq!(client.get_claims_by_category(&science, &5, &10).len(), 0);
+    assert_eq!(client.get_claims_by_status(&ClaimStatus::Pending, &0, &10).len(), 3);
+
+    assert_eq!(client.get_index_size(&ClaimIndex::Submitter(alice.clone())), 2);
+    assert_eq!(client.get_index_size(&ClaimIndex::Status(ClaimStatus::Pending)), 3);
+
+    // Status changes move claims between indexes; a removed claim's slot goes to the index's last claim
+    client.increment_review_count(&review_contract, &first);
+    assert_eq!(ids(client.get_claims_by_status(&ClaimStatus::Pending, &0, &10)), Vec::from_array(&env, [third, second]));
+    client.update_claim_status(&review_contract, &second, &ClaimStatus::False);
+    assert_eq!(ids(client.get_claims_by_status(&ClaimStatus::Pending, &0, &10)), Vec::from_array(&env, [third]));
+    assert_eq!(ids(client.get_claims_by_status(&ClaimStatus::UnderReview, &0, &10)), Vec::from_array(&env, [first]));
+    assert_eq!(ids(client.get_claims_by_status(&ClaimStatus::False, &0, &10)), Vec::from_array(&env, [second]));
+
+    // Amendments keep the claim's category
+    client.amend_claim(&alice, &third, &String::from_str(&env, "Water boils at 100C at sea level"), &Vec::new(&env));
+    assert_eq!(ids(client.get_claims_by_category(&science, &0, &10)), Vec::from_array(&env, [first, third]));
+    assert_eq!(ids(client.get_claims_by_category(&health, &0, &10)), Vec::from_array(&env, [second]));
+
+    // Withdrawals move claims to the withdrawn status index
+
+    client.withdraw_claim(&alice, &third);
+    assert_eq!(client.get_claims_by_status(&ClaimStatus::Pending, &0, &10).len(), 0);
+    assert_eq!(ids(client.get_claims_by_status(&ClaimStatus::Withdrawn, &0, &10)), Vec::from_array(&env, [third]));
+}
+
+#[test]
+fn test_error_codes() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ClaimRegistry);
+    let client = ClaimRegistryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let outsider = Address::generate(&env);
+    let review_contract = Address::generate(&env);
+    let (token_address, _, _) = create_token(&env, &admin);
+
+    client.initialize(&admin, &token_address);
+    assert_eq!(client.try_initialize(&admin, &token_address), Err(Ok(Error::AlreadyInitialized)));
+
+    // Status updates need the review consensus contract to be configured first
+    assert_eq!(
+        client.try_update_claim_status(&review_contract, &1, &ClaimStatus::True),
+        Err(Ok(Error::ReviewContractNotSet))
+    );
+
+    assert_eq!(client.try_set_review_consensus(&outsider, &review_contract), Err(Ok(Error::NotAuthorized)));
+    client.set_review_consensus(&admin, &review_contract);
+
+    assert_eq!(
+        client.try_update_claim_status(&outsider, &1, &ClaimStatus::True),
+        Err(Ok(Error::NotAuthorized))
+    );
+    assert_eq!(
+        client.try_update_claim_status(&review_contract, &1, &ClaimStatus::True),
+        Err(Ok(Error::ClaimNotFound))
+    );
+    assert_eq!(client.try_increment_review_count(&review_contract, &1), Err(Ok(Error::ClaimNotFound)));
+    assert_eq!(client.try_release_stake_pool(&outsider, &1), Err(Ok(Error::NotAuthorized)));
+    assert_eq!(client.try_release_stake_pool(&review_contract, &1), Err(Ok(Error::ClaimNotFound)));
+    assert_eq!(client.try_refund_stake_pool(&outsider, &1), Err(Ok(Error::NotAuthorized)));
+    assert_eq!(client.try_refund_stake_pool(&review_contract, &1), Err(Ok(Error::ClaimNotFound)));
+    assert_eq!(client.try_withdraw_claim(&outsider, &1), Err(Ok(Error::ClaimNotFound)));
+}
+
+#[test]
+fn test_claim_ttl_management() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ClaimRegistry);
+    let client = ClaimRegistryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let submitter = Address::generate(&env);
+    let (token_address, token_admin, _) = create_token(&env, &admin);
+    token_admin.mint(&submitter, &10_000_000);
+
+    client.initialize(&admin, &token_address);
+    create_category(&env, &client, &admin, "science");
+
+    let text = String::from_str(&env, "The Earth is round");
+    let category = String::from_str(&env, "science");
+    let claim_id = client.submit_claim(&submitter, &text, &category, &Vec::new(&env));
+
+    let claim_ttl = || env.as_contract(&contract_id, || env.storage().persistent().get_ttl(&DataKey::Claim(claim_id)));
+    assert_eq!(claim_ttl(), PERSISTENT_BUMP_AMOUNT);
+
+    // Reads within the threshold window extend the record again
+    env.ledger().with_mut(|li| li.sequence_number += 2 * DAY_IN_LEDGERS);
+    assert_eq!(claim_ttl(), PERSISTENT_BUMP_AMOUNT - 2 * DAY_IN_LEDGERS);
+    client.get_claim(&claim_id);
+    assert_eq!(claim_ttl(), PERSISTENT_BUMP_AMOUNT);
+
+    // Admin can bump records directly; unknown ids are ignored
+    env.ledger().with_mut(|li| li.sequence_number += 2 * DAY_IN_LEDGERS);
+    let mut claim_ids = Vec::new(&env);
+    claim_ids.push_back(claim_id);
+    claim_ids.push_back(99);
+
+    let outsider = Address::generate(&env);
+    assert_eq!(client.try_bump_ttl(&outsider, &claim_ids), Err(Ok(Error::NotAuthorized)));
+
+    client.bump_ttl(&admin, &claim_ids);
+    assert_eq!(claim_ttl(), PERSISTENT_BUMP_AMOUNT);
 }
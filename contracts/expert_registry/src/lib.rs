@@ -1,6 +1,28 @@
 #![no_std]
 
-use soroban_sdk::{contract, contractimpl, contracttype, Address, Env, String, Vec, symbol_short};
+use soroban_sdk::{contract, contractclient, contracterror, contractimpl, contracttype, token, Address, Env, IntoVal, String, Val, Vec, symbol_short};
+
+// Contract errors, numbered 2xx so they stay distinct from the other contracts' errors
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    AlreadyInitialized = 201,
+    NotInitialized = 202,
+    NotAuthorized = 203,
+    ExpertAlreadyRegistered = 204,
+    ExpertNotFound = 205,
+    StakeTooLow = 206,
+    InvalidAmount = 207,
+    UnstakeAlreadyRequested = 208,
+    NoUnstakeRequest = 209,
+    CooldownNotElapsed = 210,
+    StakeLocked = 211,
+    InsufficientFreeStake = 212,
+    ClaimRegistryNotSet = 213,
+    CategoryNotFound = 214,
+    CategoryRetired = 215,
+}
 
 // Expert level based on stake amount
 #[contracttype]
@@ -31,6 +53,7 @@ pub struct Expert {
     pub bio: String,
     pub expertise_categories: Vec<String>,
     pub staked_amount: i128,
+    pub locked_stake: i128,
     pub expert_level: ExpertLevel,
     pub reputation_points: i64,
     pub reputation_level: ReputationLevel,
@@ -40,15 +63,59 @@ pub struct Expert {
     pub registered_at: u64,
 }
 
+// Pending stake withdrawal
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct UnstakeRequest {
+    pub amount: i128,
+    pub available_at: u64,
+}
+
+// Index over registered experts
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ExpertIndex {
+    All,
+    Category(String), // Experts with expertise in a category slug
+}
+
 // Storage keys
 #[contracttype]
 pub enum DataKey {
     Expert(Address),
     ExpertCount,
     Admin,
+    Token,
+    ReviewConsensusContract,
+    AuthorizedCaller(Address),
     MinStakeGeneral,
     MinStakeSpecialized,
     MinStakeProfessional,
+    UnstakeCooldown,
+    UnstakeRequest(Address),
+    ClaimRegistryContract,
+    IndexSize(ExpertIndex),              // Maps index -> number of experts in it
+    IndexEntry(ExpertIndex, u32),        // Maps (index, position) -> expert
+    IndexPosition(ExpertIndex, Address), // Maps (index, expert) -> position
+}
+
+// Claim category as stored by the claim registry
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct Category {
+    pub slug: String,
+    pub name: String,
+    pub description: String,
+    pub claim_fee: i128,
+    pub min_reviews: u32,
+    pub min_expert_level: ExpertLevel,
+    pub active: bool,
+}
+
+// Claim registry entrypoints used to validate expertise
+#[contractclient(name = "ClaimRegistryClient")]
+pub trait ClaimRegistryInterface {
+    fn get_category(env: Env, slug: String) -> Option<Category>;
 }
 
 // Minimum stakes in stroops
@@ -56,22 +123,97 @@ const MIN_STAKE_GENERAL: i128 = 1_000_000_000;      // 100 XLM
 const MIN_STAKE_SPECIALIZED: i128 = 5_000_000_000;  // 500 XLM
 const MIN_STAKE_PROFESSIONAL: i128 = 10_000_000_000; // 1000 XLM
 
+// Cooldown between requesting and withdrawing stake (7 days)
+const UNSTAKE_COOLDOWN: u64 = 604_800;
+
+// Storage TTLs in ledgers (~5s per ledger)
+const DAY_IN_LEDGERS: u32 = 17_280;
+const INSTANCE_BUMP_AMOUNT: u32 = 7 * DAY_IN_LEDGERS;
+const INSTANCE_LIFETIME_THRESHOLD: u32 = INSTANCE_BUMP_AMOUNT - DAY_IN_LEDGERS;
+const PERSISTENT_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+const PERSISTENT_LIFETIME_THRESHOLD: u32 = PERSISTENT_BUMP_AMOUNT - DAY_IN_LEDGERS;
+
 #[contract]
 pub struct ExpertRegistry;
 
 #[contractimpl]
 impl ExpertRegistry {
-    /// Initialize the contract
-    pub fn initialize(env: Env, admin: Address) {
+    /// Initialize the contract with admin and the token used for stakes
+    pub fn initialize(env: Env, admin: Address, token: Address) -> Result<(), Error> {
         if env.storage().instance().has(&DataKey::Admin) {
-            panic!("Already initialized");
+            return Err(Error::AlreadyInitialized);
         }
-        
+
         env.storage().instance().set(&DataKey::Admin, &admin);
         env.storage().instance().set(&DataKey::ExpertCount, &0u64);
         env.storage().instance().set(&DataKey::MinStakeGeneral, &MIN_STAKE_GENERAL);
         env.storage().instance().set(&DataKey::MinStakeSpecialized, &MIN_STAKE_SPECIALIZED);
         env.storage().instance().set(&DataKey::MinStakeProfessional, &MIN_STAKE_PROFESSIONAL);
+        env.storage().instance().set(&DataKey::UnstakeCooldown, &UNSTAKE_COOLDOWN);
+        env.storage().instance().set(&DataKey::Token, &token);
+        Self::extend_instance_ttl(&env);
+
+        Ok(())
+    }
+
+    /// Set the review consensus contract address, revoking the one it replaces
+    pub fn set_review_consensus(env: Env, admin: Address, review_contract: Address) -> Result<(), Error> {
+        Self::require_admin(&env, &admin)?;
+
+        let previous: Option<Address> = env.storage().instance().get(&DataKey::ReviewConsensusContract);
+        if let Some(previous) = previous {
+            env.storage().instance().remove(&DataKey::AuthorizedCaller(previous));
+        }
+        env.storage().instance().set(&DataKey::ReviewConsensusContract, &review_contract);
+        env.storage().instance().set(&DataKey::AuthorizedCaller(review_contract), &true);
+        Self::extend_instance_ttl(&env);
+
+        Ok(())
+    }
+
+    /// Set the claim registry whose categories expertise is validated against
+    pub fn set_claim_registry(env: Env, admin: Address, claim_contract: Address) -> Result<(), Error> {
+        Self::require_admin(&env, &admin)?;
+
+        env.storage().instance().set(&DataKey::ClaimRegistryContract, &claim_contract);
+        Self::extend_instance_ttl(&env);
+
+        Ok(())
+    }
+
+    /// Allow a contract to update reputation, earnings and stakes
+    pub fn add_authorized_caller(env: Env, admin: Address, caller: Address) -> Result<(), Error> {
+        Self::require_admin(&env, &admin)?;
+
+        env.storage().instance().set(&DataKey::AuthorizedCaller(caller), &true);
+        Self::extend_instance_ttl(&env);
+
+        Ok(())
+    }
+
+    /// Revoke a previously authorized caller
+    pub fn remove_authorized_caller(env: Env, admin: Address, caller: Address) -> Result<(), Error> {
+        Self::require_admin(&env, &admin)?;
+
+        env.storage().instance().remove(&DataKey::AuthorizedCaller(caller));
+        Self::extend_instance_ttl(&env);
+
+        Ok(())
+    }
+
+    /// Check if address may call the privileged expert mutators
+    pub fn is_authorized_caller(env: Env, caller: Address) -> bool {
+        env.storage().instance().get(&DataKey::AuthorizedCaller(caller)).unwrap_or(false)
+    }
+
+    /// Set the cooldown (in seconds) between requesting and withdrawing stake
+    pub fn set_unstake_cooldown(env: Env, admin: Address, cooldown: u64) -> Result<(), Error> {
+        Self::require_admin(&env, &admin)?;
+
+        env.storage().instance().set(&DataKey::UnstakeCooldown, &cooldown);
+        Self::extend_instance_ttl(&env);
+
+        Ok(())
     }
 
     /// Register as an expert with stake
@@ -82,19 +224,34 @@ impl ExpertRegistry {
         bio: String,
         expertise_categories: Vec<String>,
         stake_amount: i128,
-    ) -> bool {
+    ) -> Result<bool, Error> {
         expert.require_auth();
 
         // Check if already registered
-        if env.storage().instance().has(&DataKey::Expert(expert.clone())) {
-            panic!("Expert already registered");
+        if env.storage().persistent().has(&DataKey::Expert(expert.clone())) {
+            return Err(Error::ExpertAlreadyRegistered);
         }
 
         // Determine expert level based on stake
-        let expert_level = Self::determine_expert_level(&env, stake_amount);
+        let expert_level = Self::determine_expert_level(&env, stake_amount)?;
+
+        // Every area of expertise must be an active category
+        if !expertise_categories.is_empty() {
+            let claim_contract: Address = env.storage().instance()
+                .get(&DataKey::ClaimRegistryContract)
+                .ok_or(Error::ClaimRegistryNotSet)?;
+            let claim_registry = ClaimRegistryClient::new(&env, &claim_contract);
+
+            for slug in expertise_categories.iter() {
+                let category = claim_registry.get_category(&slug).ok_or(Error::CategoryNotFound)?;
+                if !category.active {
+                    return Err(Error::CategoryRetired);
+                }
+            }
+        }
 
-        // Note: In production, transfer stake from expert to contract
-        // using Stellar Asset Contract
+        // Transfer stake from expert to contract
+        Self::token_client(&env)?.transfer(&expert, &env.current_contract_address(), &stake_amount);
 
         let expert_profile = Expert {
             address: expert.clone(),
@@ -102,6 +259,7 @@ impl ExpertRegistry {
             bio,
             expertise_categories,
             staked_amount: stake_amount,
+            locked_stake: 0,
             expert_level,
             reputation_points: 0,
             reputation_level: ReputationLevel::Seedling,
@@ -111,7 +269,12 @@ impl ExpertRegistry {
             registered_at: env.ledger().timestamp(),
         };
 
-        env.storage().instance().set(&DataKey::Expert(expert.clone()), &expert_profile);
+        Self::write_expert(&env, &expert_profile);
+
+        Self::index_add(&env, &ExpertIndex::All, &expert);
+        for slug in expert_profile.expertise_categories.iter() {
+            Self::index_add(&env, &ExpertIndex::Category(slug), &expert);
+        }
 
         let mut expert_count: u64 = env.storage().instance().get(&DataKey::ExpertCount).unwrap_or(0);
         expert_count += 1;
@@ -119,24 +282,45 @@ impl ExpertRegistry {
 
         env.events().publish((symbol_short!("expert_r"),), expert);
 
-        true
+        Ok(true)
     }
 
     /// Get expert profile
     pub fn get_expert(env: Env, expert: Address) -> Option<Expert> {
-        env.storage().instance().get(&DataKey::Expert(expert))
+        Self::read_expert(&env, &expert)
     }
 
     /// Check if address is a registered expert
     pub fn is_expert(env: Env, expert: Address) -> bool {
-        env.storage().instance().has(&DataKey::Expert(expert))
+        env.storage().persistent().has(&DataKey::Expert(expert))
+    }
+
+    /// Check if a registered expert lists the category among their expertise
+    pub fn has_expertise(env: Env, expert: Address, category: String) -> bool {
+        Self::read_expert(&env, &expert)
+            .is_some_and(|profile| profile.expertise_categories.contains(category))
+    }
+
+    /// Get the registered experts (paginated; removals reorder the index)
+    pub fn get_experts(env: Env, start: u32, limit: u32) -> Vec<Address> {
+        Self::read_index_page(&env, &ExpertIndex::All, start, limit)
     }
 
-    /// Update expert reputation (called after review consensus)
-    pub fn update_reputation(env: Env, expert: Address, points_change: i64, was_correct: bool) {
-        let mut expert_profile: Expert = env.storage().instance()
-            .get(&DataKey::Expert(expert.clone()))
-            .expect("Expert not found");
+    /// Get the registered experts with expertise in a category (paginated; removals reorder the index)
+    pub fn get_experts_by_category(env: Env, category: String, start: u32, limit: u32) -> Vec<Address> {
+        Self::read_index_page(&env, &ExpertIndex::Category(category), start, limit)
+    }
+
+    /// Get the number of experts in an index
+    pub fn get_index_size(env: Env, index: ExpertIndex) -> u32 {
+        env.storage().persistent().get(&DataKey::IndexSize(index)).unwrap_or(0)
+    }
+
+    /// Update expert reputation (called by an authorized contract after review consensus)
+    pub fn update_reputation(env: Env, caller: Address, expert: Address, points_change: i64, was_correct: bool) -> Result<(), Error> {
+        Self::require_authorized_caller(&env, &caller)?;
+
+        let mut expert_profile: Expert = Self::read_expert(&env, &expert).ok_or(Error::ExpertNotFound)?;
 
         expert_profile.reputation_points = expert_profile.reputation_points.saturating_add(points_change);
         
@@ -153,26 +337,30 @@ impl ExpertRegistry {
         // Update reputation level
         expert_profile.reputation_level = Self::calculate_reputation_level(expert_profile.reputation_points);
 
-        env.storage().instance().set(&DataKey::Expert(expert.clone()), &expert_profile);
+        Self::write_expert(&env, &expert_profile);
 
         env.events().publish((symbol_short!("rep_upd"),), expert);
+
+        Ok(())
     }
 
-    /// Add earnings to expert
-    pub fn add_earnings(env: Env, expert: Address, amount: i128) {
-        let mut expert_profile: Expert = env.storage().instance()
-            .get(&DataKey::Expert(expert.clone()))
-            .expect("Expert not found");
+    /// Add earnings to expert (called by an authorized contract)
+    pub fn add_earnings(env: Env, caller: Address, expert: Address, amount: i128) -> Result<(), Error> {
+        Self::require_authorized_caller(&env, &caller)?;
+
+        let mut expert_profile: Expert = Self::read_expert(&env, &expert).ok_or(Error::ExpertNotFound)?;
 
         expert_profile.total_earnings += amount;
-        env.storage().instance().set(&DataKey::Expert(expert.clone()), &expert_profile);
+        Self::write_expert(&env, &expert_profile);
+
+        Ok(())
     }
 
-    /// Slash expert stake (for incorrect reviews)
-    pub fn slash_stake(env: Env, expert: Address, amount: i128) -> i128 {
-        let mut expert_profile: Expert = env.storage().instance()
-            .get(&DataKey::Expert(expert.clone()))
-            .expect("Expert not found");
+    /// Slash expert stake for incorrect reviews (called by an authorized contract)
+    pub fn slash_stake(env: Env, caller: Address, expert: Address, amount: i128) -> Result<i128, Error> {
+        Self::require_authorized_caller(&env, &caller)?;
+
+        let mut expert_profile: Expert = Self::read_expert(&env, &expert).ok_or(Error::ExpertNotFound)?;
 
         let slash_amount = if amount > expert_profile.staked_amount {
             expert_profile.staked_amount
@@ -181,23 +369,196 @@ impl ExpertRegistry {
         };
 
         expert_profile.staked_amount -= slash_amount;
-        env.storage().instance().set(&DataKey::Expert(expert.clone()), &expert_profile);
+        if expert_profile.locked_stake > expert_profile.staked_amount {
+            expert_profile.locked_stake = expert_profile.staked_amount;
+        }
+
+        // The level follows the remaining stake; below every minimum the expert drops to the lowest level
+        expert_profile.expert_level = Self::determine_expert_level(&env, expert_profile.staked_amount)
+            .unwrap_or(ExpertLevel::General);
+        Self::write_expert(&env, &expert_profile);
 
-        slash_amount
+        // Slashed funds go to the calling contract to fund rewards
+        if slash_amount > 0 {
+            Self::token_client(&env)?.transfer(&env.current_contract_address(), &caller, &slash_amount);
+        }
+
+        Ok(slash_amount)
     }
 
     /// Add to expert stake
-    pub fn add_stake(env: Env, expert: Address, amount: i128) {
+    pub fn add_stake(env: Env, expert: Address, amount: i128) -> Result<(), Error> {
         expert.require_auth();
 
-        let mut expert_profile: Expert = env.storage().instance()
-            .get(&DataKey::Expert(expert.clone()))
-            .expect("Expert not found");
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+
+        let mut expert_profile: Expert = Self::read_expert(&env, &expert).ok_or(Error::ExpertNotFound)?;
+
+        Self::token_client(&env)?.transfer(&expert, &env.current_contract_address(), &amount);
 
         expert_profile.staked_amount += amount;
-        expert_profile.expert_level = Self::determine_expert_level(&env, expert_profile.staked_amount);
-        
-        env.storage().instance().set(&DataKey::Expert(expert.clone()), &expert_profile);
+        expert_profile.expert_level = Self::determine_expert_level(&env, expert_profile.staked_amount)?;
+
+        Self::write_expert(&env, &expert_profile);
+
+        Ok(())
+    }
+
+    /// Request to withdraw part or all of the stake after the cooldown period
+    pub fn request_unstake(env: Env, expert: Address, amount: i128) -> Result<u64, Error> {
+        expert.require_auth();
+
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+
+        if env.storage().persistent().has(&DataKey::UnstakeRequest(expert.clone())) {
+            return Err(Error::UnstakeAlreadyRequested);
+        }
+
+        let expert_profile: Expert = Self::read_expert(&env, &expert).ok_or(Error::ExpertNotFound)?;
+
+        if amount > expert_profile.staked_amount - expert_profile.locked_stake {
+            return Err(Error::StakeLocked);
+        }
+
+        // Remaining stake must still qualify for a level, unless fully exiting
+        let remaining = expert_profile.staked_amount - amount;
+        if remaining > 0 {
+            Self::determine_expert_level(&env, remaining)?;
+        }
+
+        let cooldown: u64 = env.storage().instance().get(&DataKey::UnstakeCooldown).unwrap_or(UNSTAKE_COOLDOWN);
+        let available_at = env.ledger().timestamp() + cooldown;
+
+        let request = UnstakeRequest { amount, available_at };
+        let key = DataKey::UnstakeRequest(expert.clone());
+        env.storage().persistent().set(&key, &request);
+        env.storage().persistent().extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+        Self::extend_instance_ttl(&env);
+
+        env.events().publish((symbol_short!("unstake_r"),), expert);
+
+        Ok(available_at)
+    }
+
+    /// Withdraw previously requested stake once the cooldown has elapsed, capped at the stake not locked in reviews
+    pub fn withdraw_stake(env: Env, expert: Address) -> Result<i128, Error> {
+        expert.require_auth();
+
+        let request: UnstakeRequest = env.storage().persistent()
+            .get(&DataKey::UnstakeRequest(expert.clone()))
+            .ok_or(Error::NoUnstakeRequest)?;
+
+        if env.ledger().timestamp() < request.available_at {
+            return Err(Error::CooldownNotElapsed);
+        }
+
+        let mut expert_profile: Expert = Self::read_expert(&env, &expert).ok_or(Error::ExpertNotFound)?;
+
+        // A slash during the cooldown can leave less than was requested; pay out what is not locked
+        let amount = request.amount.min(expert_profile.staked_amount - expert_profile.locked_stake);
+        if amount <= 0 && expert_profile.staked_amount > 0 {
+            return Err(Error::StakeLocked);
+        }
+
+        expert_profile.staked_amount -= amount;
+        env.storage().persistent().remove(&DataKey::UnstakeRequest(expert.clone()));
+
+        if expert_profile.staked_amount == 0 {
+            // Full exit removes the expert from the registry
+            env.storage().persistent().remove(&DataKey::Expert(expert.clone()));
+
+            Self::index_remove(&env, &ExpertIndex::All, &expert);
+            for slug in expert_profile.expertise_categories.iter() {
+                Self::index_remove(&env, &ExpertIndex::Category(slug), &expert);
+            }
+
+            let expert_count: u64 = env.storage().instance().get(&DataKey::ExpertCount).unwrap_or(0);
+            env.storage().instance().set(&DataKey::ExpertCount, &expert_count.saturating_sub(1));
+            Self::extend_instance_ttl(&env);
+        } else {
+            // After a slash the remaining stake can be below every minimum, as in slash_stake
+            expert_profile.expert_level = Self::determine_expert_level(&env, expert_profile.staked_amount)
+                .unwrap_or(ExpertLevel::General);
+            Self::write_expert(&env, &expert_profile);
+        }
+
+        if amount > 0 {
+            Self::token_client(&env)?.transfer(&env.current_contract_address(), &expert, &amount);
+        }
+
+        env.events().publish((symbol_short!("unstaked"),), expert);
+
+        Ok(amount)
+    }
+
+    /// Cancel a pending unstake request, returning its amount to the free stake
+    pub fn cancel_unstake(env: Env, expert: Address) -> Result<(), Error> {
+        expert.require_auth();
+
+        let key = DataKey::UnstakeRequest(expert.clone());
+        if !env.storage().persistent().has(&key) {
+            return Err(Error::NoUnstakeRequest);
+        }
+
+        env.storage().persistent().remove(&key);
+
+        env.events().publish((symbol_short!("unstake_c"),), expert);
+
+        Ok(())
+    }
+
+    /// Get pending unstake request for an expert
+    pub fn get_unstake_request(env: Env, expert: Address) -> Option<UnstakeRequest> {
+        env.storage().persistent().get(&DataKey::UnstakeRequest(expert))
+    }
+
+    /// Get stake that is neither locked in reviews nor pending withdrawal
+    pub fn get_free_stake(env: Env, expert: Address) -> Result<i128, Error> {
+        let expert_profile: Expert = Self::read_expert(&env, &expert).ok_or(Error::ExpertNotFound)?;
+
+        let pending: i128 = env.storage().persistent()
+            .get::<_, UnstakeRequest>(&DataKey::UnstakeRequest(expert))
+            .map(|request| request.amount)
+            .unwrap_or(0);
+
+        Ok((expert_profile.staked_amount - expert_profile.locked_stake - pending).max(0))
+    }
+
+    /// Lock stake backing an open review (called by an authorized contract)
+    pub fn lock_stake(env: Env, caller: Address, expert: Address, amount: i128) -> Result<(), Error> {
+        Self::require_authorized_caller(&env, &caller)?;
+
+        let mut expert_profile: Expert = Self::read_expert(&env, &expert).ok_or(Error::ExpertNotFound)?;
+
+        if amount > Self::get_free_stake(env.clone(), expert.clone())? {
+            return Err(Error::InsufficientFreeStake);
+        }
+
+        expert_profile.locked_stake += amount;
+        Self::write_expert(&env, &expert_profile);
+
+        Ok(())
+    }
+
+    /// Release stake once a review is settled (called by an authorized contract)
+    pub fn unlock_stake(env: Env, caller: Address, expert: Address, amount: i128) -> Result<(), Error> {
+        Self::require_authorized_caller(&env, &caller)?;
+
+        let mut expert_profile: Expert = Self::read_expert(&env, &expert).ok_or(Error::ExpertNotFound)?;
+
+        expert_profile.locked_stake = (expert_profile.locked_stake - amount).max(0);
+        Self::write_expert(&env, &expert_profile);
+
+        Ok(())
+    }
+
+    /// Get the token used for stakes
+    pub fn get_token(env: Env) -> Result<Address, Error> {
+        env.storage().instance().get(&DataKey::Token).ok_or(Error::NotInitialized)
     }
 
     /// Get expert count
@@ -206,33 +567,175 @@ impl ExpertRegistry {
     }
 
     /// Get accuracy percentage
-    pub fn get_accuracy(env: Env, expert: Address) -> u32 {
-        let expert_profile: Expert = env.storage().instance()
-            .get(&DataKey::Expert(expert))
-            .expect("Expert not found");
+    pub fn get_accuracy(env: Env, expert: Address) -> Result<u32, Error> {
+        let expert_profile: Expert = Self::read_expert(&env, &expert).ok_or(Error::ExpertNotFound)?;
 
         if expert_profile.total_reviews == 0 {
-            return 0;
+            return Ok(0);
         }
 
-        (expert_profile.correct_reviews * 100) / expert_profile.total_reviews
+        Ok((expert_profile.correct_reviews * 100) / expert_profile.total_reviews)
+    }
+
+    /// Extend the TTL of expert records at risk of archival
+    pub fn bump_ttl(env: Env, admin: Address, experts: Vec<Address>) -> Result<(), Error> {
+        Self::require_admin(&env, &admin)?;
+
+        for expert in experts.iter() {
+            if let Some(profile) = Self::read_expert(&env, &expert) {
+                let mut indexes = Vec::from_array(&env, [ExpertIndex::All]);
+                for slug in profile.expertise_categories.iter() {
+                    indexes.push_back(ExpertIndex::Category(slug));
+                }
+
+                for index in indexes.iter() {
+                    let position: Option<u32> = env.storage().persistent()
+                        .get(&DataKey::IndexPosition(index.clone(), expert.clone()));
+                    if let Some(position) = position {
+                        Self::extend_persistent_ttl(&env, &DataKey::IndexSize(index.clone()));
+                        Self::extend_persistent_ttl(&env, &DataKey::IndexEntry(index.clone(), position));
+                        Self::extend_persistent_ttl(&env, &DataKey::IndexPosition(index, expert.clone()));
+                    }
+                }
+            }
+
+            for key in [DataKey::Expert(expert.clone()), DataKey::UnstakeRequest(expert)] {
+                Self::extend_persistent_ttl(&env, &key);
+            }
+        }
+        Self::extend_instance_ttl(&env);
+
+        Ok(())
     }
 
     // Helper functions
 
-    fn determine_expert_level(env: &Env, stake_amount: i128) -> ExpertLevel {
+    fn read_expert(env: &Env, expert: &Address) -> Option<Expert> {
+        let key = DataKey::Expert(expert.clone());
+        let expert_profile: Option<Expert> = env.storage().persistent().get(&key);
+        if expert_profile.is_some() {
+            env.storage().persistent().extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+        }
+        expert_profile
+    }
+
+    fn write_expert(env: &Env, expert_profile: &Expert) {
+        let key = DataKey::Expert(expert_profile.address.clone());
+        env.storage().persistent().set(&key, expert_profile);
+        env.storage().persistent().extend_ttl(&key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+        Self::extend_instance_ttl(env);
+    }
+
+    // Indexes store one entry per expert, so adding and removing touch a fixed number of entries
+    fn index_add(env: &Env, index: &ExpertIndex, expert: &Address) {
+        let position_key = DataKey::IndexPosition(index.clone(), expert.clone());
+        if env.storage().persistent().has(&position_key) {
+            return;
+        }
+
+        let size = Self::get_index_size(env.clone(), index.clone());
+        Self::write_index_key(env, &DataKey::IndexEntry(index.clone(), size), expert);
+        Self::write_index_key(env, &position_key, &size);
+        Self::write_index_key(env, &DataKey::IndexSize(index.clone()), &(size + 1));
+    }
+
+    // The index's last expert moves into the removed expert's position
+    fn index_remove(env: &Env, index: &ExpertIndex, expert: &Address) {
+        let position_key = DataKey::IndexPosition(index.clone(), expert.clone());
+        let position: u32 = match env.storage().persistent().get(&position_key) {
+            Some(position) => position,
+            None => return,
+        };
+
+        let last = Self::get_index_size(env.clone(), index.clone()) - 1;
+        if position != last {
+            let last_expert: Address = env.storage().persistent().get(&DataKey::IndexEntry(index.clone(), last)).unwrap();
+            Self::write_index_key(env, &DataKey::IndexEntry(index.clone(), position), &last_expert);
+            Self::write_index_key(env, &DataKey::IndexPosition(index.clone(), last_expert), &position);
+        }
+
+        env.storage().persistent().remove(&DataKey::IndexEntry(index.clone(), last));
+        env.storage().persistent().remove(&position_key);
+        Self::write_index_key(env, &DataKey::IndexSize(index.clone()), &last);
+    }
+
+    fn write_index_key<V: IntoVal<Env, Val>>(env: &Env, key: &DataKey, value: &V) {
+        env.storage().persistent().set(key, value);
+        env.storage().persistent().extend_ttl(key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+    }
+
+    fn read_index_page(env: &Env, index: &ExpertIndex, start: u32, limit: u32) -> Vec<Address> {
+        let mut experts = Vec::new(env);
+
+        let end = Self::get_index_size(env.clone(), index.clone()).min(start.saturating_add(limit));
+        for position in start..end {
+            let expert: Option<Address> = env.storage().persistent().get(&DataKey::IndexEntry(index.clone(), position));
+            if let Some(expert) = expert {
+                experts.push_back(expert);
+            }
+        }
+
+        experts
+    }
+
+    fn extend_persistent_ttl(env: &Env, key: &DataKey) {
+        if env.storage().persistent().has(key) {
+            env.storage().persistent().extend_ttl(key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+        }
+    }
+
+    fn extend_instance_ttl(env: &Env) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+    }
+
+    fn token_client(env: &Env) -> Result<token::Client<'_>, Error> {
+        let token_address: Address = env.storage().instance()
+            .get(&DataKey::Token)
+            .ok_or(Error::NotInitialized)?;
+        Ok(token::Client::new(env, &token_address))
+    }
+
+    fn require_admin(env: &Env, admin: &Address) -> Result<(), Error> {
+        admin.require_auth();
+
+        let stored_admin: Address = env.storage().instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+
+        if *admin != stored_admin {
+            return Err(Error::NotAuthorized);
+        }
+
+        Ok(())
+    }
+
+    fn require_authorized_caller(env: &Env, caller: &Address) -> Result<(), Error> {
+        caller.require_auth();
+
+        let authorized: bool = env.storage().instance()
+            .get(&DataKey::AuthorizedCaller(caller.clone()))
+            .unwrap_or(false);
+
+        if !authorized {
+            return Err(Error::NotAuthorized);
+        }
+
+        Ok(())
+    }
+
+    fn determine_expert_level(env: &Env, stake_amount: i128) -> Result<ExpertLevel, Error> {
         let min_professional: i128 = env.storage().instance().get(&DataKey::MinStakeProfessional).unwrap_or(MIN_STAKE_PROFESSIONAL);
         let min_specialized: i128 = env.storage().instance().get(&DataKey::MinStakeSpecialized).unwrap_or(MIN_STAKE_SPECIALIZED);
         let min_general: i128 = env.storage().instance().get(&DataKey::MinStakeGeneral).unwrap_or(MIN_STAKE_GENERAL);
 
         if stake_amount >= min_professional {
-            ExpertLevel::Professional
+            Ok(ExpertLevel::Professional)
         } else if stake_amount >= min_specialized {
-            ExpertLevel::Specialized
+            Ok(ExpertLevel::Specialized)
         } else if stake_amount >= min_general {
-            ExpertLevel::General
+            Ok(ExpertLevel::General)
         } else {
-            panic!("Stake amount too low");
+            Err(Error::StakeTooLow)
         }
     }
 
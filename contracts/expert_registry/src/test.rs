@@ -1,7 +1,23 @@
 #![cfg(test)]
 
 use super::*;
-use soroban_sdk::{testutils::Address as _, Address, Env, String, Vec};
+use claim_registry::{ClaimRegistry, ClaimRegistryClient as ClaimRegistryContractClient};
+use soroban_sdk::{testutils::{Address as _, Ledger}, token::{StellarAssetClient, TokenClient}, Address, Env, String, Vec};
+
+fn create_token<'a>(env: &Env, admin: &Address) -> (Address, StellarAssetClient<'a>, TokenClient<'a>) {
+    let token_address = env.register_stellar_asset_contract_v2(admin.clone()).address();
+    (
+        token_address.clone(),
+        StellarAssetClient::new(env, &token_address),
+        TokenClient::new(env, &token_address),
+    )
+}
+
+fn register(env: &Env, client: &ExpertRegistryClient, expert: &Address, stake: i128) {
+    let name = String::from_str(env, "Dr. Smith");
+    let bio = String::from_str(env, "PhD in Physics");
+    client.register_expert(expert, &name, &bio, &Vec::new(env), &stake);
+}
 
 #[test]
 fn test_initialize() {
@@ -10,73 +26,531 @@ fn test_initialize() {
     let client = ExpertRegistryClient::new(&env, &contract_id);
 
     let admin = Address::generate(&env);
-    client.initialize(&admin);
+    let (token_address, _, _) = create_token(&env, &admin);
+    client.initialize(&admin, &token_address);
 
     assert_eq!(client.get_expert_count(), 0);
+    assert_eq!(client.get_token(), token_address);
 }
 
 #[test]
 fn test_register_expert() {
     let env = Env::default();
     env.mock_all_auths();
-    
+
     let contract_id = env.register_contract(None, ExpertRegistry);
     let client = ExpertRegistryClient::new(&env, &contract_id);
 
     let admin = Address::generate(&env);
     let expert = Address::generate(&env);
-    
-    client.initialize(&admin);
+    let (token_address, token_admin, token) = create_token(&env, &admin);
+    token_admin.mint(&expert, &2_000_000_000);
+
+    client.initialize(&admin, &token_address);
 
     let name = String::from_str(&env, "Dr. Smith");
     let bio = String::from_str(&env, "PhD in Physics");
+    let science = String::from_str(&env, "science");
     let mut categories = Vec::new(&env);
-    categories.push_back(String::from_str(&env, "Science"));
-    
+    categories.push_back(science.clone());
+
     let stake = 1_000_000_000i128; // 100 XLM
 
+    // Expertise is validated against the claim registry's categories
+    assert_eq!(
+        client.try_register_expert(&expert, &name, &bio, &categories, &stake),
+        Err(Ok(Error::ClaimRegistryNotSet))
+    );
+
+    let claims = ClaimRegistryContractClient::new(&env, &env.register_contract(None, ClaimRegistry));
+    claims.initialize(&admin, &token_address);
+    client.set_claim_registry(&admin, &claims.address);
+    assert_eq!(
+        client.try_register_expert(&expert, &name, &bio, &categories, &stake),
+        Err(Ok(Error::CategoryNotFound))
+    );
+
+    let description = String::from_str(&env, "Natural sciences");
+    claims.create_category(&admin, &science, &science, &description, &5_000_000, &3, &claim_registry::ExpertLevel::General);
+    claims.create_category(&admin, &String::from_str(&env, "alchemy"), &science, &description, &5_000_000, &3, &claim_registry::ExpertLevel::General);
+    claims.retire_category(&admin, &String::from_str(&env, "alchemy"));
+
+    let mut retired = categories.clone();
+    retired.push_back(String::from_str(&env, "alchemy"));
+    assert_eq!(
+        client.try_register_expert(&expert, &name, &bio, &retired, &stake),
+        Err(Ok(Error::CategoryRetired))
+    );
+
     let result = client.register_expert(&expert, &name, &bio, &categories, &stake);
-    assert_eq!(result, true);
+    assert!(result);
     assert_eq!(client.get_expert_count(), 1);
 
     let profile = client.get_expert(&expert).unwrap();
     assert_eq!(profile.expert_level, ExpertLevel::General);
     assert_eq!(profile.reputation_level, ReputationLevel::Seedling);
+    assert_eq!(profile.expertise_categories, categories);
+
+    // Experts are indexed by their areas of expertise
+    assert!(client.has_expertise(&expert, &science));
+    assert!(!client.has_expertise(&expert, &String::from_str(&env, "alchemy")));
+    assert_eq!(client.get_experts(&0, &10), Vec::from_array(&env, [expert.clone()]));
+    assert_eq!(client.get_experts_by_category(&science, &0, &10), Vec::from_array(&env, [expert.clone()]));
+    assert_eq!(client.get_experts_by_category(&String::from_str(&env, "alchemy"), &0, &10).len(), 0);
+    assert_eq!(client.get_index_size(&ExpertIndex::Category(science.clone())), 1);
+
+    // Stake moved from expert to contract custody
+    assert_eq!(token.balance(&expert), 1_000_000_000);
+    assert_eq!(token.balance(&contract_id), stake);
+}
+
+#[test]
+fn test_add_stake_upgrades_level() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ExpertRegistry);
+    let client = ExpertRegistryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let expert = Address::generate(&env);
+    let (token_address, token_admin, token) = create_token(&env, &admin);
+    token_admin.mint(&expert, &5_000_000_000);
+
+    client.initialize(&admin, &token_address);
+    register(&env, &client, &expert, 1_000_000_000);
+
+    client.add_stake(&expert, &4_000_000_000);
+
+    let profile = client.get_expert(&expert).unwrap();
+    assert_eq!(profile.staked_amount, 5_000_000_000);
+    assert_eq!(profile.expert_level, ExpertLevel::Specialized);
+    assert_eq!(token.balance(&contract_id), 5_000_000_000);
 }
 
 #[test]
 fn test_update_reputation() {
     let env = Env::default();
     env.mock_all_auths();
-    
+
     let contract_id = env.register_contract(None, ExpertRegistry);
     let client = ExpertRegistryClient::new(&env, &contract_id);
 
     let admin = Address::generate(&env);
     let expert = Address::generate(&env);
-    
-    client.initialize(&admin);
+    let (token_address, token_admin, _) = create_token(&env, &admin);
+    token_admin.mint(&expert, &1_000_000_000);
 
-    let name = String::from_str(&env, "Dr. Smith");
-    let bio = String::from_str(&env, "PhD in Physics");
-    let categories = Vec::new(&env);
-    let stake = 1_000_000_000i128;
+    let review_contract = Address::generate(&env);
+
+    client.initialize(&admin, &token_address);
+    client.set_review_consensus(&admin, &review_contract);
+    register(&env, &client, &expert, 1_000_000_000);
 
-    client.register_expert(&expert, &name, &bio, &categories, &stake);
-    
     // Correct review: +10 points
-    client.update_reputation(&expert, &10, &true);
-    
+    client.update_reputation(&review_contract, &expert, &10, &true);
+
     let profile = client.get_expert(&expert).unwrap();
     assert_eq!(profile.reputation_points, 10);
     assert_eq!(profile.total_reviews, 1);
     assert_eq!(profile.correct_reviews, 1);
 
     // Incorrect review: -20 points
-    client.update_reputation(&expert, &-20, &false);
-    
+    client.update_reputation(&review_contract, &expert, &-20, &false);
+
     let profile = client.get_expert(&expert).unwrap();
     assert_eq!(profile.reputation_points, 0); // Can't go below 0
     assert_eq!(profile.total_reviews, 2);
     assert_eq!(profile.correct_reviews, 1);
 }
+
+#[test]
+fn test_unstake_after_cooldown_downgrades_level() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ExpertRegistry);
+    let client = ExpertRegistryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let expert = Address::generate(&env);
+    let (token_address, token_admin, token) = create_token(&env, &admin);
+    token_admin.mint(&expert, &5_000_000_000);
+
+    client.initialize(&admin, &token_address);
+    client.set_unstake_cooldown(&admin, &100);
+    register(&env, &client, &expert, 5_000_000_000);
+
+    env.ledger().set_timestamp(1_000);
+    let available_at = client.request_unstake(&expert, &4_000_000_000);
+    assert_eq!(available_at, 1_100);
+    assert_eq!(client.get_free_stake(&expert), 1_000_000_000);
+
+    // Cooldown not yet elapsed
+    env.ledger().set_timestamp(1_099);
+    assert_eq!(client.try_withdraw_stake(&expert), Err(Ok(Error::CooldownNotElapsed)));
+
+    env.ledger().set_timestamp(1_100);
+    assert_eq!(client.withdraw_stake(&expert), 4_000_000_000);
+
+    let profile = client.get_expert(&expert).unwrap();
+    assert_eq!(profile.staked_amount, 1_000_000_000);
+    assert_eq!(profile.expert_level, ExpertLevel::General);
+    assert!(client.get_unstake_request(&expert).is_none());
+    assert_eq!(token.balance(&expert), 4_000_000_000);
+    assert_eq!(token.balance(&contract_id), 1_000_000_000);
+}
+
+#[test]
+fn test_full_unstake_removes_expert() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ExpertRegistry);
+    let client = ExpertRegistryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let expert = Address::generate(&env);
+    let others = [Address::generate(&env), Address::generate(&env)];
+    let (token_address, token_admin, token) = create_token(&env, &admin);
+
+    client.initialize(&admin, &token_address);
+    for address in [&expert, &others[0], &others[1]] {
+        token_admin.mint(address, &1_000_000_000);
+        register(&env, &client, address, 1_000_000_000);
+    }
+
+    assert_eq!(client.get_experts(&0, &10), Vec::from_array(&env, [expert.clone(), others[0].clone(), others[1].clone()]));
+    assert_eq!(client.get_experts(&1, &1), Vec::from_array(&env, [others[0].clone()]));
+
+    client.request_unstake(&expert, &1_000_000_000);
+    env.ledger().set_timestamp(UNSTAKE_COOLDOWN);
+    client.withdraw_stake(&expert);
+
+    // The last expert takes the departed expert's place in the index
+    assert!(!client.is_expert(&expert));
+    assert_eq!(client.get_expert_count(), 2);
+    assert_eq!(client.get_experts(&0, &10), Vec::from_array(&env, [others[1].clone(), others[0].clone()]));
+    assert_eq!(client.get_index_size(&ExpertIndex::All), 2);
+    assert_eq!(token.balance(&expert), 1_000_000_000);
+}
+
+#[test]
+fn test_unstake_below_minimum_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ExpertRegistry);
+    let client = ExpertRegistryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let expert = Address::generate(&env);
+    let (token_address, token_admin, _) = create_token(&env, &admin);
+    token_admin.mint(&expert, &1_500_000_000);
+
+    client.initialize(&admin, &token_address);
+    register(&env, &client, &expert, 1_500_000_000);
+
+    // Leaving 0.5 XLM short of the General minimum is not allowed
+    assert_eq!(client.try_request_unstake(&expert, &1_000_000_000), Err(Ok(Error::StakeTooLow)));
+}
+
+#[test]
+fn test_locked_stake_blocks_withdrawal() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ExpertRegistry);
+    let client = ExpertRegistryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let expert = Address::generate(&env);
+    let review_contract = Address::generate(&env);
+    let (token_address, token_admin, _) = create_token(&env, &admin);
+    token_admin.mint(&expert, &2_000_000_000);
+
+    client.initialize(&admin, &token_address);
+    client.set_review_consensus(&admin, &review_contract);
+    register(&env, &client, &expert, 2_000_000_000);
+
+    // Stake locked in an open review cannot be requested for withdrawal
+    client.lock_stake(&review_contract, &expert, &1_500_000_000);
+    assert_eq!(client.get_free_stake(&expert), 500_000_000);
+    assert_eq!(client.try_request_unstake(&expert, &1_000_000_000), Err(Ok(Error::StakeLocked)));
+
+    client.unlock_stake(&review_contract, &expert, &1_500_000_000);
+    client.request_unstake(&expert, &1_000_000_000);
+
+    // Stake pending withdrawal cannot be locked in a new review
+    assert_eq!(client.get_free_stake(&expert), 1_000_000_000);
+    assert_eq!(client.try_lock_stake(&review_contract, &expert, &1_500_000_000), Err(Ok(Error::InsufficientFreeStake)));
+
+    env.ledger().set_timestamp(UNSTAKE_COOLDOWN);
+    assert_eq!(client.withdraw_stake(&expert), 1_000_000_000);
+}
+
+#[test]
+fn test_slash_after_unstake_request() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ExpertRegistry);
+    let client = ExpertRegistryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let expert = Address::generate(&env);
+    let review_contract = Address::generate(&env);
+    let (token_address, token_admin, token) = create_token(&env, &admin);
+    token_admin.mint(&expert, &2_000_000_000);
+
+    client.initialize(&admin, &token_address);
+    client.set_review_consensus(&admin, &review_contract);
+    register(&env, &client, &expert, 2_000_000_000);
+
+    client.request_unstake(&expert, &1_000_000_000);
+    client.lock_stake(&review_contract, &expert, &500_000_000);
+    client.slash_stake(&review_contract, &expert, &800_000_000);
+    assert_eq!(client.try_request_unstake(&expert, &100_000_000), Err(Ok(Error::UnstakeAlreadyRequested)));
+
+    // Only the 0.7 XLM left unlocked is paid out, leaving the expert below the General minimum
+    env.ledger().set_timestamp(UNSTAKE_COOLDOWN);
+    assert_eq!(client.withdraw_stake(&expert), 700_000_000);
+
+    let profile = client.get_expert(&expert).unwrap();
+    assert_eq!(profile.staked_amount, 500_000_000);
+    assert_eq!(profile.locked_stake, 500_000_000);
+    assert_eq!(profile.expert_level, ExpertLevel::General);
+    assert!(client.get_unstake_request(&expert).is_none());
+    assert_eq!(token.balance(&expert), 700_000_000);
+
+    // Once the review settles, the rest can leave in a full exit
+    client.unlock_stake(&review_contract, &expert, &500_000_000);
+    client.request_unstake(&expert, &500_000_000);
+    env.ledger().set_timestamp(2 * UNSTAKE_COOLDOWN);
+    assert_eq!(client.withdraw_stake(&expert), 500_000_000);
+    assert!(!client.is_expert(&expert));
+    assert_eq!(token.balance(&expert), 1_200_000_000);
+}
+
+#[test]
+fn test_cancel_unstake() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ExpertRegistry);
+    let client = ExpertRegistryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let expert = Address::generate(&env);
+    let (token_address, token_admin, _) = create_token(&env, &admin);
+    token_admin.mint(&expert, &2_000_000_000);
+
+    client.initialize(&admin, &token_address);
+    register(&env, &client, &expert, 2_000_000_000);
+
+    assert_eq!(client.try_cancel_unstake(&expert), Err(Ok(Error::NoUnstakeRequest)));
+
+    client.request_unstake(&expert, &1_000_000_000);
+    assert_eq!(client.get_free_stake(&expert), 1_000_000_000);
+
+    client.cancel_unstake(&expert);
+    assert!(client.get_unstake_request(&expert).is_none());
+    assert_eq!(client.get_free_stake(&expert), 2_000_000_000);
+    assert_eq!(client.try_withdraw_stake(&expert), Err(Ok(Error::NoUnstakeRequest)));
+
+    // A new request can be made right away
+    client.request_unstake(&expert, &500_000_000);
+    assert_eq!(client.get_unstake_request(&expert).unwrap().amount, 500_000_000);
+}
+
+#[test]
+fn test_lock_stake_requires_review_contract() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ExpertRegistry);
+    let client = ExpertRegistryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let expert = Address::generate(&env);
+    let review_contract = Address::generate(&env);
+    let (token_address, token_admin, _) = create_token(&env, &admin);
+    token_admin.mint(&expert, &1_000_000_000);
+
+    client.initialize(&admin, &token_address);
+    client.set_review_consensus(&admin, &review_contract);
+    register(&env, &client, &expert, 1_000_000_000);
+
+    let attacker = Address::generate(&env);
+    assert_eq!(client.try_lock_stake(&attacker, &expert, &1_000_000_000), Err(Ok(Error::NotAuthorized)));
+}
+
+#[test]
+fn test_mutators_reject_unauthorized_caller() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ExpertRegistry);
+    let client = ExpertRegistryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let expert = Address::generate(&env);
+    let review_contract = Address::generate(&env);
+    let (token_address, token_admin, token) = create_token(&env, &admin);
+    token_admin.mint(&expert, &1_000_000_000);
+
+    client.initialize(&admin, &token_address);
+    client.set_review_consensus(&admin, &review_contract);
+    register(&env, &client, &expert, 1_000_000_000);
+
+    // Any other account, including the expert themselves, is rejected
+    for caller in [Address::generate(&env), expert.clone()] {
+        assert_eq!(client.try_update_reputation(&caller, &expert, &1_000, &true), Err(Ok(Error::NotAuthorized)));
+        assert_eq!(client.try_add_earnings(&caller, &expert, &1_000), Err(Ok(Error::NotAuthorized)));
+        assert_eq!(client.try_slash_stake(&caller, &expert, &1_000_000_000), Err(Ok(Error::NotAuthorized)));
+    }
+
+    let profile = client.get_expert(&expert).unwrap();
+    assert_eq!(profile.reputation_points, 0);
+    assert_eq!(profile.total_earnings, 0);
+    assert_eq!(profile.staked_amount, 1_000_000_000);
+    assert_eq!(token.balance(&contract_id), 1_000_000_000);
+
+    // Replacing the review contract revokes the old one
+    let new_review_contract = Address::generate(&env);
+    client.set_review_consensus(&admin, &new_review_contract);
+    assert!(!client.is_authorized_caller(&review_contract));
+    assert_eq!(client.try_add_earnings(&review_contract, &expert, &1_000), Err(Ok(Error::NotAuthorized)));
+    client.add_earnings(&new_review_contract, &expert, &1_000);
+    assert_eq!(client.get_expert(&expert).unwrap().total_earnings, 1_000);
+}
+
+#[test]
+fn test_slash_stake_downgrades_level() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ExpertRegistry);
+    let client = ExpertRegistryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let expert = Address::generate(&env);
+    let review_contract = Address::generate(&env);
+    let (token_address, token_admin, _) = create_token(&env, &admin);
+    token_admin.mint(&expert, &10_000_000_000);
+
+    client.initialize(&admin, &token_address);
+    client.set_review_consensus(&admin, &review_contract);
+    register(&env, &client, &expert, 10_000_000_000);
+    assert_eq!(client.get_expert(&expert).unwrap().expert_level, ExpertLevel::Professional);
+
+    // Dropping below a tier's minimum loses that tier
+    client.slash_stake(&review_contract, &expert, &1);
+    assert_eq!(client.get_expert(&expert).unwrap().expert_level, ExpertLevel::Specialized);
+
+    client.slash_stake(&review_contract, &expert, &5_000_000_000);
+    assert_eq!(client.get_expert(&expert).unwrap().expert_level, ExpertLevel::General);
+
+    // Below every minimum the expert stays at the lowest level
+    client.slash_stake(&review_contract, &expert, &4_999_999_999);
+    let profile = client.get_expert(&expert).unwrap();
+    assert_eq!(profile.staked_amount, 0);
+    assert_eq!(profile.expert_level, ExpertLevel::General);
+}
+
+#[test]
+#[should_panic]
+fn test_slash_stake_requires_caller_auth() {
+    let env = Env::default();
+
+    let contract_id = env.register_contract(None, ExpertRegistry);
+    let client = ExpertRegistryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let expert = Address::generate(&env);
+    let review_contract = Address::generate(&env);
+    let (token_address, token_admin, _) = create_token(&env, &admin);
+
+    env.mock_all_auths();
+    token_admin.mint(&expert, &1_000_000_000);
+    client.initialize(&admin, &token_address);
+    client.set_review_consensus(&admin, &review_contract);
+    register(&env, &client, &expert, 1_000_000_000);
+
+    // Naming the authorized caller without its signature is not enough
+    env.set_auths(&[]);
+    client.slash_stake(&review_contract, &expert, &1_000_000_000);
+}
+
+#[test]
+fn test_authorized_caller_management() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ExpertRegistry);
+    let client = ExpertRegistryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let expert = Address::generate(&env);
+    let appeals_contract = Address::generate(&env);
+    let (token_address, token_admin, token) = create_token(&env, &admin);
+    token_admin.mint(&expert, &1_000_000_000);
+
+    client.initialize(&admin, &token_address);
+    register(&env, &client, &expert, 1_000_000_000);
+
+    // Only the admin can grant access
+    assert_eq!(client.try_add_authorized_caller(&expert, &expert), Err(Ok(Error::NotAuthorized)));
+
+    client.add_authorized_caller(&admin, &appeals_contract);
+    assert!(client.is_authorized_caller(&appeals_contract));
+
+    assert_eq!(client.slash_stake(&appeals_contract, &expert, &100_000_000), 100_000_000);
+    assert_eq!(token.balance(&appeals_contract), 100_000_000);
+
+    client.remove_authorized_caller(&admin, &appeals_contract);
+    assert!(!client.is_authorized_caller(&appeals_contract));
+    assert_eq!(client.try_slash_stake(&appeals_contract, &expert, &100_000_000), Err(Ok(Error::NotAuthorized)));
+}
+
+#[test]
+fn test_error_codes() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ExpertRegistry);
+    let client = ExpertRegistryClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let expert = Address::generate(&env);
+    let (token_address, token_admin, _) = create_token(&env, &admin);
+    token_admin.mint(&expert, &2_000_000_000);
+
+    client.initialize(&admin, &token_address);
+    assert_eq!(client.try_initialize(&admin, &token_address), Err(Ok(Error::AlreadyInitialized)));
+
+    let name = String::from_str(&env, "Dr. Smith");
+    let bio = String::from_str(&env, "PhD in Physics");
+    let categories = Vec::new(&env);
+
+    assert_eq!(
+        client.try_register_expert(&expert, &name, &bio, &categories, &999_999_999),
+        Err(Ok(Error::StakeTooLow))
+    );
+    assert_eq!(client.try_add_stake(&expert, &1), Err(Ok(Error::ExpertNotFound)));
+    assert_eq!(client.try_get_accuracy(&expert), Err(Ok(Error::ExpertNotFound)));
+
+    client.register_expert(&expert, &name, &bio, &categories, &1_000_000_000);
+    assert_eq!(
+        client.try_register_expert(&expert, &name, &bio, &categories, &1_000_000_000),
+        Err(Ok(Error::ExpertAlreadyRegistered))
+    );
+
+    assert_eq!(client.try_add_stake(&expert, &0), Err(Ok(Error::InvalidAmount)));
+    assert_eq!(client.try_request_unstake(&expert, &-1), Err(Ok(Error::InvalidAmount)));
+    assert_eq!(client.try_withdraw_stake(&expert), Err(Ok(Error::NoUnstakeRequest)));
+
+    client.add_stake(&expert, &1_000_000_000);
+    client.request_unstake(&expert, &500_000_000);
+    assert_eq!(client.try_request_unstake(&expert, &500_000_000), Err(Ok(Error::UnstakeAlreadyRequested)));
+}
@@ -1,13 +1,60 @@
 #![no_std]
 
-use soroban_sdk::{contract, contractimpl, contracttype, Address, Env, String, Vec, symbol_short, Map};
+use soroban_sdk::{contract, contractclient, contracterror, contractimpl, contracttype, token, xdr::ToXdr, Address, BytesN, Env, IntoVal, String, TryFromVal, Val, Vec, symbol_short};
 
-// Verdict type
+// Contract errors, numbered 3xx (registry errors from cross-contract calls are 1xx and 2xx)
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    AlreadyInitialized = 301,
+    NotInitialized = 302,
+    NotAuthorized = 303,
+    InvalidConfidence = 304,
+    InvalidStakeAmount = 305,
+    ExpertNotRegistered = 306,
+    ClaimNotFound = 307,
+    ClaimNotOpen = 308,
+    InsufficientFreeStake = 309,
+    AlreadyReviewed = 310,
+    ConsensusNotReached = 311,
+    ClaimRegistryNotSet = 312,
+    ExpertRegistryNotSet = 313,
+    CommitmentNotFound = 314,
+    AlreadyRevealed = 315,
+    CommitPhaseClosed = 316,
+    CommitPhaseOpen = 317,
+    RevealPhaseClosed = 318,
+    RevealPhaseOpen = 319,
+    InvalidReveal = 320,
+    AlreadyFinalized = 321,
+    InsufficientReviews = 322,
+    InvalidThreshold = 323,
+    ExpertLevelTooLow = 324,
+    AppealBondTooLow = 325,
+    AppealLimitReached = 326,
+    AlreadySettled = 327,
+    NothingToClaim = 328,
+    InsufficientTreasury = 329,
+    InvalidAmount = 330,
+    ArithmeticOverflow = 331,
+    ClaimNotExpired = 332,
+    QuorumReached = 333,
+    ExpertiseMismatch = 334,
+    AppealWindowOpen = 335,
+    AppealWindowClosed = 336,
+    CommitmentExpired = 337,
+}
+
+// Verdict type; variants are encoded by name, so new ones are only ever appended
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum Verdict {
     True,
     False,
+    Misleading,
+    Unverifiable,
+    PartiallyTrue,
 }
 
 // Review structure
@@ -16,6 +63,7 @@ pub enum Verdict {
 pub struct Review {
     pub id: u64,
     pub claim_id: u64,
+    pub claim_version: u32, // Version of the claim the review judged
     pub expert: Address,
     pub verdict: Verdict,
     pub reasoning: String,
@@ -25,16 +73,136 @@ pub struct Review {
     pub rewarded: bool,
 }
 
-// Consensus result
+// Sealed review awaiting reveal
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct Commitment {
+    pub claim_id: u64,
+    pub claim_version: u32,
+    pub round: u32,
+    pub window: u32, // Extension of the round's review window the commitment was made in
+    pub expert: Address,
+    pub hash: BytesN<32>,
+    pub stake_amount: i128,
+    pub timestamp: u64,
+    pub revealed: bool,
+    pub settled: bool,
+}
+
+// Review window for a claim: opened by the first commitment, finalizable after the reveal deadline
 #[contracttype]
 #[derive(Clone, Debug)]
+pub struct ReviewWindow {
+    pub opens_at: u64,
+    pub commit_deadline: u64,
+    pub reveal_deadline: u64,
+    pub extensions: u32,
+}
+
+// How each revealed review is weighted when computing consensus
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ConsensusStrategy {
+    StakeOnly,          // weight = stake
+    ReputationWeighted, // weight = reputation level multiplier
+    ConfidenceWeighted, // weight = stake * confidence / 100
+    QuadraticStake,     // weight = sqrt(stake)
+}
+
+// Which experts may review a claim, based on the claim's category
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ExpertisePolicy {
+    Strict,              // Expertise must include the category
+    MatchOrProfessional, // Matching expertise, or any Professional expert
+    Open,                // Any registered expert
+}
+
+// Outcome of a review round
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ConsensusOutcome {
+    Resolved(Verdict),
+    Inconclusive, // Tie, or the leading verdict missed the supermajority
+}
+
+// Stake and consensus weight behind one verdict
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VerdictTally {
+    pub verdict: Verdict,
+    pub total_stake: i128,
+    pub weight: i128,
+}
+
+// Consensus result
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub struct ConsensusResult {
     pub claim_id: u64,
-    pub final_verdict: Verdict,
-    pub total_stake_true: i128,
-    pub total_stake_false: i128,
+    pub round: u32,
+    pub outcome: ConsensusOutcome,
+    pub strategy: ConsensusStrategy,
+    pub tallies: Vec<VerdictTally>,
     pub confidence_percentage: u32,
     pub is_finalized: bool,
+    pub appeal_deadline: u64, // Appeals close and settlement opens at this time
+}
+
+// Appeal state
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AppealStatus {
+    Pending,
+    Upheld,   // Verdict flipped; bond refunded
+    Rejected, // Verdict stood or was inconclusive; bond forfeited to the reward pool
+    Lapsed,   // Round missed its quorum; previous verdict restored, bond forfeited to the reward pool
+}
+
+// Appeal opening a new review round on a finalized claim
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct Appeal {
+    pub claim_id: u64,
+    pub round: u32,
+    pub appellant: Address,
+    pub bond: i128,
+    pub previous_outcome: ConsensusOutcome,
+    pub status: AppealStatus,
+    pub timestamp: u64,
+}
+
+// Stage of a claim's settlement
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SettlementPhase {
+    Forfeit, // Forfeiting unrevealed commitments, round by round
+    Slash,   // Slashing incorrect reviews and totalling the winning stake
+    Credit,  // Crediting correct reviews from the reward pool
+}
+
+// Progress of a claim's settlement, carried across distribute_rewards calls
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct Settlement {
+    pub phase: SettlementPhase,
+    pub round: u32,  // Round whose commitments are being forfeited
+    pub cursor: u32, // Next commitment or review to settle
+    pub total_slashed: i128,
+    pub total_winning_stake: i128,
+    pub bounty: i128,
+    pub reward_pool: i128,
+    pub total_credited: i128,
+}
+
+// Index over revealed reviews and committers
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ReviewIndex {
+    Claim(u64),            // Review ids of a claim, across rounds
+    Window(u64, u32, u32), // Review ids revealed in one window (extension) of a claim's round
+    Expert(Address),       // Review ids of an expert
+    Commitments(u64, u32), // Experts who committed to a claim's round
 }
 
 // Storage keys
@@ -42,10 +210,19 @@ pub struct ConsensusResult {
 pub enum DataKey {
     Review(u64),
     ReviewCount,
-    ClaimReviews(u64),        // Maps claim_id -> Vec<review_ids>
-    ExpertReviews(Address),   // Maps expert -> Vec<review_ids>
-    ConsensusResult(u64),     // Maps claim_id -> ConsensusResult
+    IndexSize(ReviewIndex),       // Maps index -> number of entries in it
+    IndexEntry(ReviewIndex, u32), // Maps (index, position) -> review_id, or expert for commitments
+    ExpertReviewPosition(u64),    // Maps review_id -> its position in the expert's index
+    ConsensusResult(u64, u32),    // Maps (claim_id, round) -> ConsensusResult
+    Commitment(u64, Address),     // Maps (claim_id, expert) -> Commitment
+    ReviewWindow(u64),            // Maps claim_id -> ReviewWindow of the current round
+    ClaimRound(u64),              // Maps claim_id -> current round
+    Appeal(u64, u32),             // Maps (claim_id, round) -> Appeal
+    Settlement(u64),              // Maps claim_id -> Settlement in progress
+    RewardsDistributed(u64),      // Set once a claim has been settled
+    ClaimableRewards(Address),    // Maps expert -> rewards credited but not yet withdrawn
     Admin,
+    Token,
     ClaimRegistryContract,
     ExpertRegistryContract,
     MinReviewsForConsensus,
@@ -53,25 +230,161 @@ pub enum DataKey {
     SlashPercentage,
     CorrectReviewPoints,
     IncorrectReviewPoints,
+    CommitPeriod,
+    RevealPeriod,
+    ConsensusStrategy,
+    SupermajorityThreshold,
+    MaxExtensions,
+    MinAppealBond,
+    AppealPeriod,
+    TreasuryBalance,
+    ClaimExpiry,
+    ExpertisePolicy,
+}
+
+// Claim status as stored by the claim registry
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ClaimStatus {
+    Pending,
+    UnderReview,
+    True,
+    False,
+    Inconclusive,
+    Misleading,
+    Unverifiable,
+    PartiallyTrue,
+    Withdrawn,
+    Expired,
+}
+
+// Claim record as returned by the claim registry
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct Claim {
+    pub id: u64,
+    pub submitter: Address,
+    pub text: String,
+    pub category: String,
+    pub sources: Vec<String>,
+    pub status: ClaimStatus,
+    pub stake_pool: i128,
+    pub timestamp: u64,
+    pub review_count: u32,
+    pub version: u32,
+}
+
+// Expert level as stored by the expert registry
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ExpertLevel {
+    General,
+    Specialized,
+    Professional,
+}
+
+// Reputation level as stored by the expert registry
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ReputationLevel {
+    Seedling,
+    Sprout,
+    Established,
+    Expert,
+    Master,
+}
+
+// Expert profile as returned by the expert registry
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct Expert {
+    pub address: Address,
+    pub name: String,
+    pub bio: String,
+    pub expertise_categories: Vec<String>,
+    pub staked_amount: i128,
+    pub locked_stake: i128,
+    pub expert_level: ExpertLevel,
+    pub reputation_points: i64,
+    pub reputation_level: ReputationLevel,
+    pub total_reviews: u32,
+    pub correct_reviews: u32,
+    pub total_earnings: i128,
+    pub registered_at: u64,
+}
+
+// Claim category as stored by the claim registry
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct Category {
+    pub slug: String,
+    pub name: String,
+    pub description: String,
+    pub claim_fee: i128,
+    pub min_reviews: u32,
+    pub min_expert_level: ExpertLevel,
+    pub active: bool,
+}
+
+// Claim registry entrypoints used by reviews
+#[contractclient(name = "ClaimRegistryClient")]
+pub trait ClaimRegistryInterface {
+    fn get_claim(env: Env, claim_id: u64) -> Option<Claim>;
+    fn get_category(env: Env, slug: String) -> Option<Category>;
+    fn increment_review_count(env: Env, caller: Address, claim_id: u64);
+    fn update_claim_status(env: Env, caller: Address, claim_id: u64, new_status: ClaimStatus);
+    fn release_stake_pool(env: Env, caller: Address, claim_id: u64) -> i128;
+    fn refund_stake_pool(env: Env, caller: Address, claim_id: u64) -> i128;
+}
+
+// Expert registry entrypoints used by reviews and settlement
+#[contractclient(name = "ExpertRegistryClient")]
+pub trait ExpertRegistryInterface {
+    fn get_expert(env: Env, expert: Address) -> Option<Expert>;
+    fn is_expert(env: Env, expert: Address) -> bool;
+    fn get_free_stake(env: Env, expert: Address) -> i128;
+    fn get_experts(env: Env, start: u32, limit: u32) -> Vec<Address>;
+    fn get_experts_by_category(env: Env, category: String, start: u32, limit: u32) -> Vec<Address>;
+    fn lock_stake(env: Env, caller: Address, expert: Address, amount: i128);
+    fn unlock_stake(env: Env, caller: Address, expert: Address, amount: i128);
+    fn add_earnings(env: Env, caller: Address, expert: Address, amount: i128);
+    fn slash_stake(env: Env, caller: Address, expert: Address, amount: i128) -> i128;
+    fn update_reputation(env: Env, caller: Address, expert: Address, points_change: i64, was_correct: bool);
 }
 
 const MIN_REVIEWS_FOR_CONSENSUS: u32 = 3;
-const REWARD_PERCENTAGE: u32 = 80;  // 80% of stake pool to winners
+const REWARD_PERCENTAGE: u32 = 80;  // 80% of slashed stake to winners, the rest to the treasury
 const SLASH_PERCENTAGE: u32 = 10;   // 10% slash for losers
 const CORRECT_REVIEW_POINTS: i64 = 10;
 const INCORRECT_REVIEW_POINTS: i64 = -20;
+const COMMIT_PERIOD: u64 = 259_200;  // 3 days to commit after the first commitment
+const REVEAL_PERIOD: u64 = 172_800;  // 2 days to reveal after the commit deadline
+const SUPERMAJORITY_THRESHOLD: u32 = 50;  // Winning weight must exceed 50%
+const MAX_EXTENSIONS: u32 = 1;            // Extra review rounds before a claim is inconclusive
+const MIN_APPEAL_BOND: i128 = 1_000_000_000;  // 100 XLM
+const MAX_APPEALS: u32 = 2;                   // Appeal rounds after the initial review
+const APPEAL_PERIOD: u64 = 259_200;           // 3 days to appeal a verdict before it can be settled
+const SETTLEMENT_BATCH_SIZE: u32 = 20;        // Commitments and reviews settled per distribute_rewards call
+const CLAIM_EXPIRY: u64 = 2_592_000;  // 30 days for a claim to reach consensus
+
+// Storage TTLs in ledgers (~5s per ledger)
+const DAY_IN_LEDGERS: u32 = 17_280;
+const INSTANCE_BUMP_AMOUNT: u32 = 7 * DAY_IN_LEDGERS;
+const INSTANCE_LIFETIME_THRESHOLD: u32 = INSTANCE_BUMP_AMOUNT - DAY_IN_LEDGERS;
+const PERSISTENT_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+const PERSISTENT_LIFETIME_THRESHOLD: u32 = PERSISTENT_BUMP_AMOUNT - DAY_IN_LEDGERS;
 
 #[contract]
 pub struct ReviewConsensus;
 
 #[contractimpl]
 impl ReviewConsensus {
-    /// Initialize the contract
-    pub fn initialize(env: Env, admin: Address) {
+    /// Initialize the contract with admin and the token used for rewards
+    pub fn initialize(env: Env, admin: Address, token: Address) -> Result<(), Error> {
         if env.storage().instance().has(&DataKey::Admin) {
-            panic!("Already initialized");
+            return Err(Error::AlreadyInitialized);
         }
-        
+
         env.storage().instance().set(&DataKey::Admin, &admin);
         env.storage().instance().set(&DataKey::ReviewCount, &0u64);
         env.storage().instance().set(&DataKey::MinReviewsForConsensus, &MIN_REVIEWS_FOR_CONSENSUS);
@@ -79,69 +392,267 @@ impl ReviewConsensus {
         env.storage().instance().set(&DataKey::SlashPercentage, &SLASH_PERCENTAGE);
         env.storage().instance().set(&DataKey::CorrectReviewPoints, &CORRECT_REVIEW_POINTS);
         env.storage().instance().set(&DataKey::IncorrectReviewPoints, &INCORRECT_REVIEW_POINTS);
+        env.storage().instance().set(&DataKey::CommitPeriod, &COMMIT_PERIOD);
+        env.storage().instance().set(&DataKey::RevealPeriod, &REVEAL_PERIOD);
+        env.storage().instance().set(&DataKey::ConsensusStrategy, &ConsensusStrategy::StakeOnly);
+        env.storage().instance().set(&DataKey::ExpertisePolicy, &ExpertisePolicy::Open);
+        env.storage().instance().set(&DataKey::SupermajorityThreshold, &SUPERMAJORITY_THRESHOLD);
+        env.storage().instance().set(&DataKey::MaxExtensions, &MAX_EXTENSIONS);
+        env.storage().instance().set(&DataKey::MinAppealBond, &MIN_APPEAL_BOND);
+        env.storage().instance().set(&DataKey::AppealPeriod, &APPEAL_PERIOD);
+        env.storage().instance().set(&DataKey::ClaimExpiry, &CLAIM_EXPIRY);
+        env.storage().instance().set(&DataKey::Token, &token);
+        Self::extend_instance_ttl(&env);
+
+        Ok(())
     }
 
     /// Set the claim registry contract address
-    pub fn set_claim_registry(env: Env, admin: Address, claim_contract: Address) {
-        admin.require_auth();
-        
-        let stored_admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
-        if admin != stored_admin {
-            panic!("Not authorized");
-        }
-        
+    pub fn set_claim_registry(env: Env, admin: Address, claim_contract: Address) -> Result<(), Error> {
+        Self::require_admin(&env, &admin)?;
+
         env.storage().instance().set(&DataKey::ClaimRegistryContract, &claim_contract);
+        Self::extend_instance_ttl(&env);
+
+        Ok(())
     }
 
     /// Set the expert registry contract address
-    pub fn set_expert_registry(env: Env, admin: Address, expert_contract: Address) {
-        admin.require_auth();
-        
-        let stored_admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
-        if admin != stored_admin {
-            panic!("Not authorized");
-        }
-        
+    pub fn set_expert_registry(env: Env, admin: Address, expert_contract: Address) -> Result<(), Error> {
+        Self::require_admin(&env, &admin)?;
+
         env.storage().instance().set(&DataKey::ExpertRegistryContract, &expert_contract);
+        Self::extend_instance_ttl(&env);
+
+        Ok(())
+    }
+
+    /// Set the commit and reveal phase lengths (in seconds)
+    pub fn set_review_periods(env: Env, admin: Address, commit_period: u64, reveal_period: u64) -> Result<(), Error> {
+        Self::require_admin(&env, &admin)?;
+
+        env.storage().instance().set(&DataKey::CommitPeriod, &commit_period);
+        env.storage().instance().set(&DataKey::RevealPeriod, &reveal_period);
+        Self::extend_instance_ttl(&env);
+
+        Ok(())
+    }
+
+    /// Set the strategy used to weight reviews when finalizing consensus
+    pub fn set_consensus_strategy(env: Env, admin: Address, strategy: ConsensusStrategy) -> Result<(), Error> {
+        Self::require_admin(&env, &admin)?;
+
+        env.storage().instance().set(&DataKey::ConsensusStrategy, &strategy);
+        Self::extend_instance_ttl(&env);
+
+        Ok(())
+    }
+
+    /// Get the strategy used to weight reviews
+    pub fn get_consensus_strategy(env: Env) -> ConsensusStrategy {
+        env.storage().instance().get(&DataKey::ConsensusStrategy).unwrap_or(ConsensusStrategy::StakeOnly)
+    }
+
+    /// Set which experts may review a claim based on its category
+    pub fn set_expertise_policy(env: Env, admin: Address, policy: ExpertisePolicy) -> Result<(), Error> {
+        Self::require_admin(&env, &admin)?;
+
+        env.storage().instance().set(&DataKey::ExpertisePolicy, &policy);
+        Self::extend_instance_ttl(&env);
+
+        Ok(())
+    }
+
+    /// Get the policy matching reviewers to claim categories
+    pub fn get_expertise_policy(env: Env) -> ExpertisePolicy {
+        env.storage().instance().get(&DataKey::ExpertisePolicy).unwrap_or(ExpertisePolicy::Open)
+    }
+
+    /// Set the share of weight (in percent) the winning verdict must exceed (0 for a plain plurality), and how many
+    /// extra review rounds an inconclusive claim gets before it is closed as inconclusive
+    pub fn set_supermajority(env: Env, admin: Address, threshold_percentage: u32, max_extensions: u32) -> Result<(), Error> {
+        Self::require_admin(&env, &admin)?;
+
+        if threshold_percentage >= 100 {
+            return Err(Error::InvalidThreshold);
+        }
+
+        env.storage().instance().set(&DataKey::SupermajorityThreshold, &threshold_percentage);
+        env.storage().instance().set(&DataKey::MaxExtensions, &max_extensions);
+        Self::extend_instance_ttl(&env);
+
+        Ok(())
+    }
+
+    /// Set the minimum bond required to appeal a finalized claim
+    pub fn set_min_appeal_bond(env: Env, admin: Address, min_bond: i128) -> Result<(), Error> {
+        Self::require_admin(&env, &admin)?;
+
+        env.storage().instance().set(&DataKey::MinAppealBond, &min_bond);
+        Self::extend_instance_ttl(&env);
+
+        Ok(())
     }
 
-    /// Submit a review for a claim
-    pub fn submit_review(
+    /// Set how long a finalized verdict stays open to appeal before it can be settled (admin only)
+    pub fn set_appeal_period(env: Env, admin: Address, appeal_period: u64) -> Result<(), Error> {
+        Self::require_admin(&env, &admin)?;
+
+        env.storage().instance().set(&DataKey::AppealPeriod, &appeal_period);
+        Self::extend_instance_ttl(&env);
+
+        Ok(())
+    }
+
+    /// Set how long (in seconds) a claim has to reach consensus before it can be expired
+    pub fn set_claim_expiry(env: Env, admin: Address, expiry_period: u64) -> Result<(), Error> {
+        Self::require_admin(&env, &admin)?;
+
+        env.storage().instance().set(&DataKey::ClaimExpiry, &expiry_period);
+        Self::extend_instance_ttl(&env);
+
+        Ok(())
+    }
+
+    /// Commit a sealed review for a claim, locking its stake
+    pub fn commit_review(
+        env: Env,
+        expert: Address,
+        claim_id: u64,
+        hash: BytesN<32>,
+        stake_amount: i128,
+    ) -> Result<u64, Error> {
+        expert.require_auth();
+
+        // Every review puts stake at risk, which also keeps its expert registered until settlement
+        if stake_amount <= 0 {
+            return Err(Error::InvalidStakeAmount);
+        }
+
+        let expert_registry = Self::expert_registry(&env)?;
+        let claim_registry = Self::claim_registry(&env)?;
+
+        // Check if expert is registered
+        let profile = expert_registry.get_expert(&expert).ok_or(Error::ExpertNotRegistered)?;
+
+        // Finalized rounds no longer accept reviews
+        let round = Self::current_round(&env, claim_id);
+        if env.storage().persistent().has(&DataKey::ConsensusResult(claim_id, round)) {
+            return Err(Error::AlreadyFinalized);
+        }
+
+        // Check if claim exists and is still open for review
+        let claim = claim_registry.get_claim(&claim_id).ok_or(Error::ClaimNotFound)?;
+        if claim.status != ClaimStatus::Pending && claim.status != ClaimStatus::UnderReview {
+            return Err(Error::ClaimNotOpen);
+        }
+
+        // The expert must be senior enough and qualified for the claim's category
+        let required_rank = Self::required_rank(&claim_registry, &claim, round);
+        Self::require_eligible(&env, &profile, &claim, required_rank)?;
+
+        // Check if expert has already reviewed this claim, in any round
+        let commitment_key = DataKey::Commitment(claim_id, expert.clone());
+        if env.storage().persistent().has(&commitment_key) {
+            return Err(Error::AlreadyReviewed);
+        }
+
+        // Review stake must be backed by the expert's free stake
+        if stake_amount > expert_registry.get_free_stake(&expert) {
+            return Err(Error::InsufficientFreeStake);
+        }
+
+        // The first commitment opens the claim's commit phase
+        let now = env.ledger().timestamp();
+        let window: ReviewWindow = match Self::read_persistent(&env, &DataKey::ReviewWindow(claim_id)) {
+            Some(window) => window,
+            None => Self::open_review_window(&env, claim_id),
+        };
+
+        if now >= window.commit_deadline {
+            return Err(Error::CommitPhaseClosed);
+        }
+
+        // Lock the review stake until settlement and record the review on the claim
+        let this = env.current_contract_address();
+        expert_registry.lock_stake(&this, &expert, &stake_amount);
+        claim_registry.increment_review_count(&this, &claim_id);
+
+        // Claims cannot be amended once reviewed, so this pins the version being judged
+        let commitment = Commitment {
+            claim_id,
+            claim_version: claim.version,
+            round,
+            window: window.extensions,
+            expert: expert.clone(),
+            hash,
+            stake_amount,
+            timestamp: now,
+            revealed: false,
+            settled: false,
+        };
+        Self::write_persistent(&env, &commitment_key, &commitment);
+
+        Self::index_push(&env, &ReviewIndex::Commitments(claim_id, round), &expert);
+
+        env.events().publish((symbol_short!("commit"), claim_id), expert);
+
+        Ok(window.commit_deadline)
+    }
+
+    /// Reveal a committed review once the commit phase has closed
+    pub fn reveal_review(
         env: Env,
         expert: Address,
         claim_id: u64,
         verdict: Verdict,
         reasoning: String,
         confidence: u32,
-        stake_amount: i128,
-    ) -> u64 {
+        salt: BytesN<32>,
+    ) -> Result<u64, Error> {
         expert.require_auth();
 
         // Validate confidence is between 0-100
         if confidence > 100 {
-            panic!("Confidence must be between 0 and 100");
+            return Err(Error::InvalidConfidence);
         }
 
-        // Check if expert is registered (would call expert registry)
-        // In production: call expert_registry.is_expert(expert)
+        let commitment_key = DataKey::Commitment(claim_id, expert.clone());
+        let mut commitment: Commitment = Self::read_persistent(&env, &commitment_key)
+            .ok_or(Error::CommitmentNotFound)?;
 
-        // Check if claim exists (would call claim registry)
-        // In production: call claim_registry.get_claim(claim_id)
+        if commitment.revealed {
+            return Err(Error::AlreadyRevealed);
+        }
 
-        // Check if expert has already reviewed this claim
-        let claim_reviews: Vec<u64> = env.storage().instance()
-            .get(&DataKey::ClaimReviews(claim_id))
-            .unwrap_or(Vec::new(&env));
+        // Commitments from an earlier round can no longer be revealed
+        if commitment.round != Self::current_round(&env, claim_id) {
+            return Err(Error::RevealPhaseClosed);
+        }
 
-        for review_id in claim_reviews.iter() {
-            let review: Review = env.storage().instance()
-                .get(&DataKey::Review(review_id))
-                .unwrap();
-            if review.expert == expert {
-                panic!("Expert has already reviewed this claim");
-            }
+        let window: ReviewWindow = Self::read_persistent(&env, &DataKey::ReviewWindow(claim_id))
+            .ok_or(Error::CommitmentNotFound)?;
+        let now = env.ledger().timestamp();
+        if now < window.commit_deadline {
+            return Err(Error::CommitPhaseOpen);
+        }
+        if now >= window.reveal_deadline {
+            return Err(Error::RevealPhaseClosed);
         }
 
+        // Commitments left unrevealed when their window was extended have been forfeited
+        if commitment.window != window.extensions {
+            return Err(Error::CommitmentExpired);
+        }
+
+        let hash = Self::compute_commitment(env.clone(), expert.clone(), claim_id, commitment.round, verdict.clone(), confidence, salt);
+        if hash != commitment.hash {
+            return Err(Error::InvalidReveal);
+        }
+
+        commitment.revealed = true;
+        Self::write_persistent(&env, &commitment_key, &commitment);
+
         // Create the review
         let mut review_count: u64 = env.storage().instance().get(&DataKey::ReviewCount).unwrap_or(0);
         review_count += 1;
@@ -149,153 +660,475 @@ impl ReviewConsensus {
         let review = Review {
             id: review_count,
             claim_id,
+            claim_version: commitment.claim_version,
             expert: expert.clone(),
-            verdict: verdict.clone(),
+            verdict,
             reasoning,
             confidence,
-            stake_amount,
-            timestamp: env.ledger().timestamp(),
+            stake_amount: commitment.stake_amount,
+            timestamp: now,
             rewarded: false,
         };
 
         // Store the review
-        env.storage().instance().set(&DataKey::Review(review_count), &review);
+        Self::write_persistent(&env, &DataKey::Review(review_count), &review);
         env.storage().instance().set(&DataKey::ReviewCount, &review_count);
 
-        // Add review to claim's review list
-        let mut claim_reviews: Vec<u64> = env.storage().instance()
-            .get(&DataKey::ClaimReviews(claim_id))
-            .unwrap_or(Vec::new(&env));
-        claim_reviews.push_back(review_count);
-        env.storage().instance().set(&DataKey::ClaimReviews(claim_id), &claim_reviews);
+        // Add review to the claim's, round's and expert's indexes
+        Self::index_push(&env, &ReviewIndex::Claim(claim_id), &review_count);
+        Self::index_push(&env, &ReviewIndex::Window(claim_id, commitment.round, commitment.window), &review_count);
+        let position = Self::index_push(&env, &ReviewIndex::Expert(expert.clone()), &review_count);
+        Self::write_index_key(&env, &DataKey::ExpertReviewPosition(review_count), &position);
 
-        // Add review to expert's review list
-        let mut expert_reviews: Vec<u64> = env.storage().instance()
-            .get(&DataKey::ExpertReviews(expert.clone()))
-            .unwrap_or(Vec::new(&env));
-        expert_reviews.push_back(review_count);
-        env.storage().instance().set(&DataKey::ExpertReviews(expert.clone()), &expert_reviews);
+        env.events().publish((symbol_short!("review_s"),), review_count);
 
-        // Check if we can reach consensus
-        let review_count_for_claim = claim_reviews.len();
-        let min_reviews: u32 = env.storage().instance()
-            .get(&DataKey::MinReviewsForConsensus)
-            .unwrap_or(MIN_REVIEWS_FOR_CONSENSUS);
+        Ok(review_count)
+    }
 
-        if review_count_for_claim >= min_reviews {
-            Self::calculate_consensus(&env, claim_id);
-        }
+    /// Compute the commitment hash: sha256(expert (ScVal XDR) || claim_id (u64) || round (u32) || verdict byte ||
+    /// confidence (u32) || salt), integers big-endian
+    ///
+    /// Binding the expert, claim and round means a copied commitment cannot be revealed by anyone else.
+    /// Verdict bytes: True 0, False 1, Misleading 2, Unverifiable 3, PartiallyTrue 4
+    pub fn compute_commitment(
+        env: Env,
+        expert: Address,
+        claim_id: u64,
+        round: u32,
+        verdict: Verdict,
+        confidence: u32,
+        salt: BytesN<32>,
+    ) -> BytesN<32> {
+        let verdict_byte: u8 = match verdict {
+            Verdict::True => 0,
+            Verdict::False => 1,
+            Verdict::Misleading => 2,
+            Verdict::Unverifiable => 3,
+            Verdict::PartiallyTrue => 4,
+        };
 
-        env.events().publish((symbol_short!("review_s"),), review_count);
+        let mut preimage = expert.to_xdr(&env);
+        preimage.extend_from_array(&claim_id.to_be_bytes());
+        preimage.extend_from_array(&round.to_be_bytes());
+        preimage.push_back(verdict_byte);
+        preimage.extend_from_array(&confidence.to_be_bytes());
+        preimage.append(&salt.into());
+
+        env.crypto().sha256(&preimage).into()
+    }
 
-        review_count
+    /// Get an expert's commitment for a claim
+    pub fn get_commitment(env: Env, claim_id: u64, expert: Address) -> Option<Commitment> {
+        Self::read_persistent(&env, &DataKey::Commitment(claim_id, expert))
+    }
+
+    /// Get the commit and reveal deadlines for a claim
+    pub fn get_review_window(env: Env, claim_id: u64) -> Option<ReviewWindow> {
+        Self::read_persistent(&env, &DataKey::ReviewWindow(claim_id))
     }
 
     /// Get a review by ID
     pub fn get_review(env: Env, review_id: u64) -> Option<Review> {
-        env.storage().instance().get(&DataKey::Review(review_id))
+        Self::read_persistent(&env, &DataKey::Review(review_id))
     }
 
-    /// Get all reviews for a claim
-    pub fn get_claim_reviews(env: Env, claim_id: u64) -> Vec<Review> {
-        let review_ids: Vec<u64> = env.storage().instance()
-            .get(&DataKey::ClaimReviews(claim_id))
-            .unwrap_or(Vec::new(&env));
+    /// Get the reviews for a claim across rounds (paginated)
+    pub fn get_claim_reviews(env: Env, claim_id: u64, start: u32, limit: u32) -> Vec<Review> {
+        Self::read_review_page(&env, &ReviewIndex::Claim(claim_id), start, limit)
+    }
 
-        let mut reviews = Vec::new(&env);
-        for review_id in review_ids.iter() {
-            if let Some(review) = env.storage().instance().get(&DataKey::Review(review_id)) {
-                reviews.push_back(review);
+    /// Get the number of entries in an index
+    pub fn get_index_size(env: Env, index: ReviewIndex) -> u32 {
+        env.storage().persistent().get(&DataKey::IndexSize(index)).unwrap_or(0)
+    }
+
+    /// Get the latest finalized consensus result for a claim
+    pub fn get_consensus(env: Env, claim_id: u64) -> Option<ConsensusResult> {
+        Self::standing_consensus(&env, claim_id)
+    }
+
+    /// Get the consensus result of every finalized round for a claim
+    pub fn get_consensus_history(env: Env, claim_id: u64) -> Vec<ConsensusResult> {
+        let mut history = Vec::new(&env);
+        for round in 0..=Self::current_round(&env, claim_id) {
+            if let Some(consensus) = Self::read_persistent(&env, &DataKey::ConsensusResult(claim_id, round)) {
+                history.push_back(consensus);
             }
         }
 
-        reviews
+        history
     }
 
-    /// Get consensus result for a claim
-    pub fn get_consensus(env: Env, claim_id: u64) -> Option<ConsensusResult> {
-        env.storage().instance().get(&DataKey::ConsensusResult(claim_id))
+    /// Get the current review round of a claim (0 until appealed)
+    pub fn get_claim_round(env: Env, claim_id: u64) -> u32 {
+        Self::current_round(&env, claim_id)
     }
 
-    /// Calculate consensus for a claim (internal)
-    fn calculate_consensus(env: &Env, claim_id: u64) {
-        let review_ids: Vec<u64> = env.storage().instance()
-            .get(&DataKey::ClaimReviews(claim_id))
-            .unwrap_or(Vec::new(env));
+    /// Get the appeal that opened a round
+    pub fn get_appeal(env: Env, claim_id: u64, round: u32) -> Option<Appeal> {
+        Self::read_persistent(&env, &DataKey::Appeal(claim_id, round))
+    }
 
-        let mut total_stake_true: i128 = 0;
-        let mut total_stake_false: i128 = 0;
+    /// Appeal a finalized claim, opening a new round before a larger and more senior panel
+    pub fn appeal(env: Env, claim_id: u64, appellant: Address, bond: i128) -> Result<u32, Error> {
+        appellant.require_auth();
 
-        // Calculate total stakes for each verdict
-        for review_id in review_ids.iter() {
-            let review: Review = env.storage().instance()
-                .get(&DataKey::Review(review_id))
-                .unwrap();
+        let round = Self::current_round(&env, claim_id);
+        if !Self::round_closed(&env, claim_id, round) {
+            return Err(Error::ConsensusNotReached);
+        }
+        let consensus = Self::standing_consensus(&env, claim_id).ok_or(Error::ConsensusNotReached)?;
 
-            match review.verdict {
-                Verdict::True => total_stake_true += review.stake_amount,
-                Verdict::False => total_stake_false += review.stake_amount,
-            }
+        // Settled claims have already paid out on the standing verdict
+        if env.storage().persistent().has(&DataKey::RewardsDistributed(claim_id)) {
+            return Err(Error::AlreadySettled);
         }
 
-        let total_stake = total_stake_true + total_stake_false;
+        if round >= MAX_APPEALS {
+            return Err(Error::AppealLimitReached);
+        }
 
-        // Determine the winning verdict (>50% of stake)
-        let (final_verdict, winning_stake) = if total_stake_true > total_stake_false {
-            (Verdict::True, total_stake_true)
-        } else {
-            (Verdict::False, total_stake_false)
-        };
+        if env.ledger().timestamp() >= consensus.appeal_deadline {
+            return Err(Error::AppealWindowClosed);
+        }
 
-        // Calculate confidence percentage
-        let confidence_percentage = if total_stake > 0 {
-            ((winning_stake * 100) / total_stake) as u32
-        } else {
-            0
-        };
+        let min_bond: i128 = env.storage().instance().get(&DataKey::MinAppealBond).unwrap_or(MIN_APPEAL_BOND);
+        if bond < min_bond {
+            return Err(Error::AppealBondTooLow);
+        }
 
-        let consensus = ConsensusResult {
+        let this = env.current_contract_address();
+        Self::token_client(&env)?.transfer(&appellant, &this, &bond);
+
+        let next_round = round + 1;
+        let appeal = Appeal {
             claim_id,
-            final_verdict: final_verdict.clone(),
-            total_stake_true,
-            total_stake_false,
-            confidence_percentage,
-            is_finalized: true,
+            round: next_round,
+            appellant,
+            bond,
+            previous_outcome: consensus.outcome,
+            status: AppealStatus::Pending,
+            timestamp: env.ledger().timestamp(),
         };
+        Self::write_persistent(&env, &DataKey::Appeal(claim_id, next_round), &appeal);
+        Self::write_persistent(&env, &DataKey::ClaimRound(claim_id), &next_round);
 
-        env.storage().instance().set(&DataKey::ConsensusResult(claim_id), &consensus);
+        // The new round's review window opens at once, so the appeal lapses if no panel forms in time
+        Self::open_review_window(&env, claim_id);
+        Self::claim_registry(&env)?.update_claim_status(&this, &claim_id, &ClaimStatus::UnderReview);
 
-        env.events().publish((symbol_short!("consensus"),), claim_id);
+        env.events().publish((symbol_short!("appeal"), claim_id), next_round);
+
+        Ok(next_round)
     }
 
-    /// Distribute rewards and update reputations after consensus
-    pub fn distribute_rewards(env: Env, admin: Address, claim_id: u64) {
-        admin.require_auth();
+    /// Finalize a claim's current round once its review window has closed (callable by anyone)
+    pub fn finalize(env: Env, claim_id: u64) -> Result<ConsensusResult, Error> {
+        let round = Self::current_round(&env, claim_id);
+        if Self::round_closed(&env, claim_id, round) {
+            return Err(Error::AlreadyFinalized);
+        }
 
-        let consensus: ConsensusResult = env.storage().instance()
-            .get(&DataKey::ConsensusResult(claim_id))
-            .expect("Consensus not reached");
+        let mut window: ReviewWindow = Self::read_persistent(&env, &DataKey::ReviewWindow(claim_id))
+            .ok_or(Error::InsufficientReviews)?;
+        let now = env.ledger().timestamp();
+        if now < window.reveal_deadline {
+            return Err(Error::RevealPhaseOpen);
+        }
 
-        let review_ids: Vec<u64> = env.storage().instance()
-            .get(&DataKey::ClaimReviews(claim_id))
-            .unwrap_or(Vec::new(&env));
+        // Only reviews revealed in the current window count towards the quorum, which doubles with every appeal
+        let review_count = Self::get_index_size(env.clone(), ReviewIndex::Window(claim_id, round, window.extensions));
 
-        let mut total_winning_stake: i128 = 0;
-        let mut total_losing_stake: i128 = 0;
+        if review_count < Self::min_reviews(&env, claim_id)? << round {
+            if round == 0 {
+                return Err(Error::InsufficientReviews);
+            }
 
-        // Calculate total winning and losing stakes
-        for review_id in review_ids.iter() {
-            let review: Review = env.storage().instance()
-                .get(&DataKey::Review(review_id))
+            // An appeal that never gathered its panel lapses: the previous verdict is restored and the
+            // bond forfeited
+            let mut appeal: Appeal = Self::read_persistent(&env, &DataKey::Appeal(claim_id, round))
                 .unwrap();
+            appeal.status = AppealStatus::Lapsed;
+            Self::write_persistent(&env, &DataKey::Appeal(claim_id, round), &appeal);
+
+            let previous = Self::standing_consensus(&env, claim_id).unwrap();
+            Self::claim_registry(&env)?
+                .update_claim_status(&env.current_contract_address(), &claim_id, &Self::claim_status(&previous.outcome));
+
+            env.events().publish((symbol_short!("lapsed"), claim_id), round);
+
+            return Ok(previous);
+        }
+
+        let mut consensus = Self::calculate_consensus(&env, claim_id, round, window.extensions)?;
+
+        // An inconclusive round is reopened for a fresh set of sealed reviews until the extensions run out;
+        // the revealed votes are public by now, so the new window is tallied on its own
+        if consensus.outcome == ConsensusOutcome::Inconclusive && window.extensions < Self::max_extensions(&env) {
+            // Commitments the window never saw revealed are forfeited rather than left to reveal later
+            let forfeited = Self::forfeit_unrevealed(&env, claim_id, round, window.extensions)?;
+            Self::credit_treasury(&env, forfeited);
+
+            let commit_period: u64 = env.storage().instance().get(&DataKey::CommitPeriod).unwrap_or(COMMIT_PERIOD);
+            let reveal_period: u64 = env.storage().instance().get(&DataKey::RevealPeriod).unwrap_or(REVEAL_PERIOD);
+
+            window.commit_deadline = now + commit_period;
+            window.reveal_deadline = now + commit_period + reveal_period;
+            window.extensions += 1;
+            Self::write_persistent(&env, &DataKey::ReviewWindow(claim_id), &window);
+
+            env.events().publish((symbol_short!("extended"), claim_id), window.commit_deadline);
 
-            if review.verdict == consensus.final_verdict {
-                total_winning_stake += review.stake_amount;
+            return Ok(consensus);
+        }
+
+        // The verdict stays open to appeal for a while, unless no appeal rounds are left
+        let appeal_period: u64 = env.storage().instance().get(&DataKey::AppealPeriod).unwrap_or(APPEAL_PERIOD);
+        consensus.appeal_deadline = if round < MAX_APPEALS { now + appeal_period } else { now };
+        consensus.is_finalized = true;
+        Self::write_persistent(&env, &DataKey::ConsensusResult(claim_id, round), &consensus);
+
+        // Only a new decisive verdict refunds the appeal bond; otherwise it is forfeited
+        if let Some(mut appeal) = Self::read_persistent::<Appeal>(&env, &DataKey::Appeal(claim_id, round)) {
+            if matches!(consensus.outcome, ConsensusOutcome::Resolved(_)) && consensus.outcome != appeal.previous_outcome {
+                Self::token_client(&env)?.transfer(&env.current_contract_address(), &appeal.appellant, &appeal.bond);
+                appeal.status = AppealStatus::Upheld;
             } else {
-                total_losing_stake += review.stake_amount;
+                appeal.status = AppealStatus::Rejected;
+            }
+            Self::write_persistent(&env, &DataKey::Appeal(claim_id, round), &appeal);
+        }
+
+        // Record the verdict on the claim itself
+        Self::claim_registry(&env)?
+            .update_claim_status(&env.current_contract_address(), &claim_id, &Self::claim_status(&consensus.outcome));
+
+        env.events().publish((symbol_short!("consensus"),), claim_id);
+
+        Ok(consensus)
+    }
+
+    /// Get the experts who may still commit a review for a claim's current round, paging over the
+    /// registry's candidates: a page holds at most `limit` reviewers, and fewer when some are ineligible
+    pub fn get_eligible_reviewers(env: Env, claim_id: u64, start: u32, limit: u32) -> Result<Vec<Address>, Error> {
+        let expert_registry = Self::expert_registry(&env)?;
+        let claim_registry = Self::claim_registry(&env)?;
+
+        let claim = claim_registry.get_claim(&claim_id).ok_or(Error::ClaimNotFound)?;
+        let round = Self::current_round(&env, claim_id);
+        let mut eligible = Vec::new(&env);
+
+        if (claim.status != ClaimStatus::Pending && claim.status != ClaimStatus::UnderReview)
+            || env.storage().persistent().has(&DataKey::ConsensusResult(claim_id, round))
+        {
+            return Ok(eligible);
+        }
+
+        // Under a strict policy only the category's experts can qualify
+        let candidates = match Self::get_expertise_policy(env.clone()) {
+            ExpertisePolicy::Strict => expert_registry.get_experts_by_category(&claim.category, &start, &limit),
+            ExpertisePolicy::MatchOrProfessional | ExpertisePolicy::Open => expert_registry.get_experts(&start, &limit),
+        };
+        let required_rank = Self::required_rank(&claim_registry, &claim, round);
+
+        for candidate in candidates.iter() {
+            // Experts who reviewed an earlier round cannot review again
+            if env.storage().persistent().has(&DataKey::Commitment(claim_id, candidate.clone())) {
+                continue;
+            }
+
+            if let Some(profile) = expert_registry.get_expert(&candidate) {
+                if Self::require_eligible(&env, &profile, &claim, required_rank).is_ok() {
+                    eligible.push_back(candidate);
+                }
+            }
+        }
+
+        Ok(eligible)
+    }
+
+    /// Expire a claim that never reached consensus, releasing revealed reviewers' stakes, forfeiting
+    /// unrevealed ones and refunding its sponsors (callable by anyone)
+    pub fn expire_claim(env: Env, claim_id: u64) -> Result<i128, Error> {
+        // Claims with a verdict, including those under appeal, settle through distribute_rewards
+        if Self::current_round(&env, claim_id) > 0
+            || env.storage().persistent().has(&DataKey::ConsensusResult(claim_id, 0))
+        {
+            return Err(Error::AlreadyFinalized);
+        }
+
+        let claim_registry = Self::claim_registry(&env)?;
+        let claim = claim_registry.get_claim(&claim_id).ok_or(Error::ClaimNotFound)?;
+        if claim.status != ClaimStatus::Pending && claim.status != ClaimStatus::UnderReview {
+            return Err(Error::ClaimNotOpen);
+        }
+
+        // The expiry never cuts a running review window short
+        let expiry_period: u64 = env.storage().instance().get(&DataKey::ClaimExpiry).unwrap_or(CLAIM_EXPIRY);
+        let window: Option<ReviewWindow> = Self::read_persistent(&env, &DataKey::ReviewWindow(claim_id));
+        let expires_at = match &window {
+            Some(window) => (claim.timestamp + expiry_period).max(window.reveal_deadline),
+            None => claim.timestamp + expiry_period,
+        };
+
+        if env.ledger().timestamp() < expires_at {
+            return Err(Error::ClaimNotExpired);
+        }
+
+        // A round with enough revealed reviews must be finalized instead
+        let extensions = window.map(|window| window.extensions).unwrap_or(0);
+        if Self::get_index_size(env.clone(), ReviewIndex::Window(claim_id, 0, extensions)) >= Self::min_reviews(&env, claim_id)? {
+            return Err(Error::QuorumReached);
+        }
+
+        // Revealed stakes are released without penalty; unrevealed commitments forfeit their stake to the treasury
+        let expert_registry = Self::expert_registry(&env)?;
+        let this = env.current_contract_address();
+        let commitments = ReviewIndex::Commitments(claim_id, 0);
+
+        let mut total_slashed: i128 = 0;
+        for position in 0..Self::get_index_size(env.clone(), commitments.clone()) {
+            let committer: Address = Self::index_get(&env, &commitments, position);
+            let commitment_key = DataKey::Commitment(claim_id, committer.clone());
+            let mut commitment: Commitment = Self::read_persistent(&env, &commitment_key)
+                .unwrap();
+
+            if commitment.settled {
+                continue;
+            }
+
+            // Experts who have since left the registry have no stake left to release
+            if expert_registry.is_expert(&committer) {
+                expert_registry.unlock_stake(&this, &committer, &commitment.stake_amount);
+
+                if !commitment.revealed {
+                    let slashed = expert_registry.slash_stake(&this, &committer, &commitment.stake_amount);
+                    total_slashed += slashed;
+
+                    env.events().publish((symbol_short!("slashed"), claim_id), (committer.clone(), slashed));
+                }
+            }
+
+            commitment.settled = true;
+            Self::write_persistent(&env, &commitment_key, &commitment);
+        }
+
+        Self::credit_treasury(&env, total_slashed);
+
+        claim_registry.update_claim_status(&this, &claim_id, &ClaimStatus::Expired);
+        let refunded = claim_registry.refund_stake_pool(&this, &claim_id);
+
+        env.events().publish((symbol_short!("expired"), claim_id), refunded);
+
+        Ok(refunded)
+    }
+
+    /// Tally the reviews revealed in a window of a claim's round (internal)
+    fn calculate_consensus(env: &Env, claim_id: u64, round: u32, window: u32) -> Result<ConsensusResult, Error> {
+        let round_reviews = ReviewIndex::Window(claim_id, round, window);
+
+        let strategy: ConsensusStrategy = env.storage().instance()
+            .get(&DataKey::ConsensusStrategy)
+            .unwrap_or(ConsensusStrategy::StakeOnly);
+
+        let mut tallies: Vec<VerdictTally> = Vec::new(env);
+        let mut total_weight: i128 = 0;
+
+        // Calculate total stakes and weights for each verdict, in order of first appearance
+        for position in 0..Self::get_index_size(env.clone(), round_reviews.clone()) {
+            let review_id: u64 = Self::index_get(env, &round_reviews, position);
+            let review: Review = Self::read_persistent(env, &DataKey::Review(review_id))
+                .unwrap();
+            let weight = Self::review_weight(env, &strategy, &review)?;
+            total_weight += weight;
+
+            match tallies.iter().position(|tally| tally.verdict == review.verdict) {
+                Some(index) => {
+                    let mut tally = tallies.get_unchecked(index as u32);
+                    tally.total_stake += review.stake_amount;
+                    tally.weight += weight;
+                    tallies.set(index as u32, tally);
+                }
+                None => tallies.push_back(VerdictTally {
+                    verdict: review.verdict,
+                    total_stake: review.stake_amount,
+                    weight,
+                }),
+            }
+        }
+
+        // Find the plurality verdict, noting whether another verdict ties it
+        let mut leader: Option<VerdictTally> = None;
+        let mut tied = false;
+        for tally in tallies.iter() {
+            match &leader {
+                Some(current) if tally.weight < current.weight => {}
+                Some(current) if tally.weight == current.weight => tied = true,
+                _ => {
+                    leader = Some(tally);
+                    tied = false;
+                }
+            }
+        }
+        let winning_weight = leader.as_ref().map_or(0, |tally| tally.weight);
+
+        let threshold: u32 = env.storage().instance()
+            .get(&DataKey::SupermajorityThreshold)
+            .unwrap_or(SUPERMAJORITY_THRESHOLD);
+
+        // The leading verdict wins only if it exceeds the supermajority threshold; ties never do
+        let outcome = match leader {
+            Some(tally) if !tied && tally.weight > 0 && tally.weight * 100 > total_weight * (threshold as i128) => {
+                ConsensusOutcome::Resolved(tally.verdict)
             }
+            _ => ConsensusOutcome::Inconclusive,
+        };
+
+        // Calculate confidence percentage
+        let confidence_percentage = if total_weight > 0 {
+            ((winning_weight * 100) / total_weight) as u32
+        } else {
+            0
+        };
+
+        Ok(ConsensusResult {
+            claim_id,
+            round,
+            outcome,
+            strategy,
+            tallies,
+            confidence_percentage,
+            is_finalized: false,
+            appeal_deadline: 0,
+        })
+    }
+
+    /// Settle a finalized claim once its appeal window has closed: slash and release stakes, update
+    /// reputations and credit winners' claimable rewards. Each call settles a bounded batch of commitments
+    /// and reviews and returns whether the claim is fully settled (callable by anyone)
+    pub fn distribute_rewards(env: Env, claim_id: u64) -> Result<bool, Error> {
+        // Every round is settled against the standing verdict, once the latest round is closed
+        let round = Self::current_round(&env, claim_id);
+        if !Self::round_closed(&env, claim_id, round) {
+            return Err(Error::ConsensusNotReached);
+        }
+        let consensus = Self::standing_consensus(&env, claim_id).ok_or(Error::ConsensusNotReached)?;
+
+        // Settling twice is a no-op
+        if env.storage().persistent().has(&DataKey::RewardsDistributed(claim_id)) {
+            return Ok(true);
+        }
+
+        if env.ledger().timestamp() < consensus.appeal_deadline {
+            return Err(Error::AppealWindowOpen);
         }
 
+        let expert_registry = Self::expert_registry(&env)?;
+
+        // Reviews from all rounds
+        let claim_reviews = ReviewIndex::Claim(claim_id);
+        let review_count = Self::get_index_size(env.clone(), claim_reviews.clone());
+
         let reward_percentage: u32 = env.storage().instance()
             .get(&DataKey::RewardPercentage)
             .unwrap_or(REWARD_PERCENTAGE);
@@ -304,9 +1137,6 @@ impl ReviewConsensus {
             .get(&DataKey::SlashPercentage)
             .unwrap_or(SLASH_PERCENTAGE);
 
-        // Calculate total reward pool (80% of total stake pool)
-        let total_reward_pool = (total_winning_stake + total_losing_stake) * (reward_percentage as i128) / 100;
-
         let correct_points: i64 = env.storage().instance()
             .get(&DataKey::CorrectReviewPoints)
             .unwrap_or(CORRECT_REVIEW_POINTS);
@@ -315,43 +1145,243 @@ impl ReviewConsensus {
             .get(&DataKey::IncorrectReviewPoints)
             .unwrap_or(INCORRECT_REVIEW_POINTS);
 
-        // Distribute rewards and update reputations
-        for review_id in review_ids.iter() {
-            let mut review: Review = env.storage().instance()
-                .get(&DataKey::Review(review_id))
+        let this = env.current_contract_address();
+        let mut settlement: Settlement = Self::read_persistent(&env, &DataKey::Settlement(claim_id))
+            .unwrap_or(Settlement {
+                phase: SettlementPhase::Forfeit,
+                round: 0,
+                cursor: 0,
+                total_slashed: 0,
+                total_winning_stake: 0,
+                bounty: 0,
+                reward_pool: 0,
+                total_credited: 0,
+            });
+        let mut budget = SETTLEMENT_BATCH_SIZE;
+
+        while settlement.phase == SettlementPhase::Forfeit && budget > 0 {
+            // Unrevealed commitments forfeit their whole stake
+            let commitments = ReviewIndex::Commitments(claim_id, settlement.round);
+            let commitment_count = Self::get_index_size(env.clone(), commitments.clone());
+
+            while settlement.cursor < commitment_count && budget > 0 {
+                let committer: Address = Self::index_get(&env, &commitments, settlement.cursor);
+                settlement.cursor += 1;
+                budget -= 1;
+
+                let commitment_key = DataKey::Commitment(claim_id, committer.clone());
+                let mut commitment: Commitment = Self::read_persistent(&env, &commitment_key)
+                    .unwrap();
+
+                if commitment.revealed || commitment.settled {
+                    continue;
+                }
+
+                // Experts who have since left the registry have no stake left to forfeit
+                if expert_registry.is_expert(&committer) {
+                    expert_registry.unlock_stake(&this, &committer, &commitment.stake_amount);
+                    let slashed = expert_registry.slash_stake(&this, &committer, &commitment.stake_amount);
+                    settlement.total_slashed += slashed;
+
+                    env.events().publish((symbol_short!("slashed"), claim_id), (committer.clone(), slashed));
+                }
+
+                commitment.settled = true;
+                Self::write_persistent(&env, &commitment_key, &commitment);
+            }
+
+            if settlement.cursor < commitment_count {
+                break;
+            }
+
+            // Bonds of failed and lapsed appeals join the reward pool
+            if let Some(appeal) = Self::read_persistent::<Appeal>(&env, &DataKey::Appeal(claim_id, settlement.round)) {
+                if matches!(appeal.status, AppealStatus::Rejected | AppealStatus::Lapsed) {
+                    settlement.total_slashed += appeal.bond;
+                }
+            }
+
+            settlement.cursor = 0;
+            if settlement.round < round {
+                settlement.round += 1;
+            } else {
+                settlement.phase = SettlementPhase::Slash;
+            }
+        }
+
+        // Slash incorrect reviewers first so the reward pool is backed by collected funds
+        while settlement.phase == SettlementPhase::Slash && budget > 0 {
+            if settlement.cursor == review_count {
+                // Reward pool is the claim's bounty (submission fee and sponsor top-ups) plus 80% of the slashed
+                // stake; inconclusive claims have no winners to pay and refund the bounty to its sponsors
+                let claim_registry = Self::claim_registry(&env)?;
+                settlement.reward_pool = match consensus.outcome {
+                    ConsensusOutcome::Resolved(_) => {
+                        settlement.bounty = claim_registry.release_stake_pool(&this, &claim_id);
+                        Self::pro_rata(settlement.total_slashed, reward_percentage as i128, 100)? + settlement.bounty
+                    }
+                    ConsensusOutcome::Inconclusive => {
+                        claim_registry.refund_stake_pool(&this, &claim_id);
+                        0
+                    }
+                };
+
+                settlement.phase = SettlementPhase::Credit;
+                settlement.cursor = 0;
+                break;
+            }
+
+            let review_id: u64 = Self::index_get(&env, &claim_reviews, settlement.cursor);
+            settlement.cursor += 1;
+            budget -= 1;
+
+            let mut review: Review = Self::read_persistent(&env, &DataKey::Review(review_id))
                 .unwrap();
 
             if review.rewarded {
-                continue; // Skip if already rewarded
+                continue; // Skip if already settled
             }
 
-            let is_correct = review.verdict == consensus.final_verdict;
-
-            if is_correct {
-                // Calculate proportional reward
-                let reward = (review.stake_amount * total_reward_pool) / total_winning_stake;
-                
-                // In production: transfer reward to expert
-                // expert_registry.add_earnings(review.expert, reward)
-                
-                // Update reputation (+10)
-                // expert_registry.update_reputation(review.expert, +10, true)
-            } else {
-                // Slash 10% of stake
-                let slash_amount = (review.stake_amount * (slash_percentage as i128)) / 100;
-                
-                // In production: slash expert stake
-                // expert_registry.slash_stake(review.expert, slash_amount)
-                
-                // Update reputation (-20)
-                // expert_registry.update_reputation(review.expert, -20, false)
+            match &consensus.outcome {
+                ConsensusOutcome::Resolved(verdict) if review.verdict == *verdict => {
+                    settlement.total_winning_stake += review.stake_amount;
+                    continue;
+                }
+                // Reviewers who have since left the registry keep nothing at stake to release or slash
+                _ if !expert_registry.is_expert(&review.expert) => {}
+                ConsensusOutcome::Resolved(_) => {
+                    expert_registry.unlock_stake(&this, &review.expert, &review.stake_amount);
+
+                    let slash_amount = Self::pro_rata(review.stake_amount, slash_percentage as i128, 100)?;
+                    let slashed = expert_registry.slash_stake(&this, &review.expert, &slash_amount);
+                    settlement.total_slashed += slashed;
+                    expert_registry.update_reputation(&this, &review.expert, &incorrect_points, &false);
+
+                    env.events().publish((symbol_short!("slashed"), claim_id), (review.expert.clone(), slashed));
+                }
+                // Inconclusive rounds release review stakes without penalty
+                ConsensusOutcome::Inconclusive => expert_registry.unlock_stake(&this, &review.expert, &review.stake_amount),
+            }
+
+            review.rewarded = true;
+            Self::write_persistent(&env, &DataKey::Review(review_id), &review);
+        }
+
+        // Credit correct reviewers proportionally to their stake
+        while settlement.phase == SettlementPhase::Credit && settlement.cursor < review_count && budget > 0 {
+            let review_id: u64 = Self::index_get(&env, &claim_reviews, settlement.cursor);
+            settlement.cursor += 1;
+            budget -= 1;
+
+            let mut review: Review = Self::read_persistent(&env, &DataKey::Review(review_id))
+                .unwrap();
+
+            if review.rewarded {
+                continue;
+            }
+
+            // Departed reviewers are still credited, but their registry record is gone
+            let registered = expert_registry.is_expert(&review.expert);
+            if registered {
+                expert_registry.unlock_stake(&this, &review.expert, &review.stake_amount);
+            }
+
+            // Shares round down, so credits never exceed the pool
+            let reward = Self::pro_rata(settlement.reward_pool, review.stake_amount, settlement.total_winning_stake)?;
+            settlement.total_credited += reward;
+
+            if reward > 0 {
+                let key = DataKey::ClaimableRewards(review.expert.clone());
+                let claimable: i128 = Self::read_persistent(&env, &key).unwrap_or(0);
+                Self::write_persistent(&env, &key, &(claimable + reward));
+                if registered {
+                    expert_registry.add_earnings(&this, &review.expert, &reward);
+                }
+
+                env.events().publish((symbol_short!("credited"), claim_id), (review.expert.clone(), reward));
+            }
+            if registered {
+                expert_registry.update_reputation(&this, &review.expert, &correct_points, &true);
             }
 
             review.rewarded = true;
-            env.storage().instance().set(&DataKey::Review(review_id), &review);
+            Self::write_persistent(&env, &DataKey::Review(review_id), &review);
         }
 
+        // Later calls pick up where this batch stopped
+        if settlement.phase != SettlementPhase::Credit || settlement.cursor < review_count {
+            Self::write_persistent(&env, &DataKey::Settlement(claim_id), &settlement);
+            return Ok(false);
+        }
+
+        // The protocol keeps whatever was not credited: its share of the slashed stake and rounding dust,
+        // or all of the slashed stake when there were no winners
+        let protocol_cut = settlement.total_slashed + settlement.bounty - settlement.total_credited;
+        Self::credit_treasury(&env, protocol_cut);
+
+        env.storage().persistent().remove(&DataKey::Settlement(claim_id));
+        Self::write_persistent(&env, &DataKey::RewardsDistributed(claim_id), &true);
+
+        env.events().publish((symbol_short!("bounty"), claim_id), settlement.bounty);
+        env.events().publish((symbol_short!("treasury"), claim_id), protocol_cut);
         env.events().publish((symbol_short!("rewards"),), claim_id);
+
+        Ok(true)
+    }
+
+    /// Get the progress of a claim's settlement, while it spans several distribute_rewards calls
+    pub fn get_settlement(env: Env, claim_id: u64) -> Option<Settlement> {
+        Self::read_persistent(&env, &DataKey::Settlement(claim_id))
+    }
+
+    /// Withdraw all rewards credited to an expert
+    pub fn claim_rewards(env: Env, expert: Address) -> Result<i128, Error> {
+        expert.require_auth();
+
+        let key = DataKey::ClaimableRewards(expert.clone());
+        let amount: i128 = Self::read_persistent(&env, &key).unwrap_or(0);
+        if amount <= 0 {
+            return Err(Error::NothingToClaim);
+        }
+
+        env.storage().persistent().remove(&key);
+        Self::token_client(&env)?.transfer(&env.current_contract_address(), &expert, &amount);
+
+        env.events().publish((symbol_short!("claimed"), expert), amount);
+
+        Ok(amount)
+    }
+
+    /// Get the rewards an expert can withdraw
+    pub fn get_claimable_rewards(env: Env, expert: Address) -> i128 {
+        Self::read_persistent(&env, &DataKey::ClaimableRewards(expert)).unwrap_or(0)
+    }
+
+    /// Get the protocol's share of fees and slashed stake held by this contract
+    pub fn get_treasury_balance(env: Env) -> i128 {
+        env.storage().instance().get(&DataKey::TreasuryBalance).unwrap_or(0)
+    }
+
+    /// Withdraw funds from the treasury
+    pub fn withdraw_treasury(env: Env, admin: Address, recipient: Address, amount: i128) -> Result<(), Error> {
+        Self::require_admin(&env, &admin)?;
+
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+
+        let balance = Self::get_treasury_balance(env.clone());
+        if amount > balance {
+            return Err(Error::InsufficientTreasury);
+        }
+
+        env.storage().instance().set(&DataKey::TreasuryBalance, &(balance - amount));
+        Self::extend_instance_ttl(&env);
+        Self::token_client(&env)?.transfer(&env.current_contract_address(), &recipient, &amount);
+
+        env.events().publish((symbol_short!("withdraw"), recipient), amount);
+
+        Ok(())
     }
 
     /// Get review count
@@ -359,21 +1389,366 @@ impl ReviewConsensus {
         env.storage().instance().get(&DataKey::ReviewCount).unwrap_or(0)
     }
 
-    /// Get expert's reviews
-    pub fn get_expert_reviews(env: Env, expert: Address) -> Vec<Review> {
-        let review_ids: Vec<u64> = env.storage().instance()
-            .get(&DataKey::ExpertReviews(expert))
-            .unwrap_or(Vec::new(&env));
+    /// Get the token used for rewards
+    pub fn get_token(env: Env) -> Result<Address, Error> {
+        env.storage().instance().get(&DataKey::Token).ok_or(Error::NotInitialized)
+    }
+
+    /// Get expert's reviews (paginated)
+    pub fn get_expert_reviews(env: Env, expert: Address, start: u32, limit: u32) -> Vec<Review> {
+        Self::read_review_page(&env, &ReviewIndex::Expert(expert), start, limit)
+    }
+
+    /// Extend the TTL of a claim's reviews, consensus and settlement, and of its reviewers' review
+    /// indexes and claimable rewards, at risk of archival
+    pub fn bump_ttl(env: Env, admin: Address, claim_ids: Vec<u64>) -> Result<(), Error> {
+        Self::require_admin(&env, &admin)?;
+
+        for claim_id in claim_ids.iter() {
+            let claim_reviews = ReviewIndex::Claim(claim_id);
+            for position in 0..Self::get_index_size(env.clone(), claim_reviews.clone()) {
+                let review_id: u64 = Self::index_get(&env, &claim_reviews, position);
+                let review: Review = Self::read_persistent(&env, &DataKey::Review(review_id)).unwrap();
+
+                // Only this review's entry of the expert's index is extended
+                let position_key = DataKey::ExpertReviewPosition(review_id);
+                let expert_position: u32 = env.storage().persistent().get(&position_key).unwrap();
+                let expert_reviews = ReviewIndex::Expert(review.expert);
+                Self::extend_persistent_ttl(&env, &position_key);
+                Self::extend_persistent_ttl(&env, &DataKey::IndexEntry(expert_reviews.clone(), expert_position));
+                Self::extend_persistent_ttl(&env, &DataKey::IndexSize(expert_reviews));
+            }
+            Self::extend_index_ttl(&env, &claim_reviews);
+
+            for round in 0..=Self::current_round(&env, claim_id) {
+                let commitments = ReviewIndex::Commitments(claim_id, round);
+                for position in 0..Self::get_index_size(env.clone(), commitments.clone()) {
+                    let committer: Address = Self::index_get(&env, &commitments, position);
+                    Self::extend_persistent_ttl(&env, &DataKey::Commitment(claim_id, committer.clone()));
+                    Self::extend_persistent_ttl(&env, &DataKey::ClaimableRewards(committer));
+                }
+                Self::extend_index_ttl(&env, &commitments);
+                for window in 0..=Self::max_extensions(&env) {
+                    Self::extend_index_ttl(&env, &ReviewIndex::Window(claim_id, round, window));
+                }
+                Self::extend_persistent_ttl(&env, &DataKey::ConsensusResult(claim_id, round));
+                Self::extend_persistent_ttl(&env, &DataKey::Appeal(claim_id, round));
+            }
+            Self::extend_persistent_ttl(&env, &DataKey::ReviewWindow(claim_id));
+            Self::extend_persistent_ttl(&env, &DataKey::Settlement(claim_id));
+            Self::extend_persistent_ttl(&env, &DataKey::RewardsDistributed(claim_id));
+            Self::extend_persistent_ttl(&env, &DataKey::ClaimRound(claim_id));
+        }
+        Self::extend_instance_ttl(&env);
+
+        Ok(())
+    }
+
+    // Helper functions
+
+    /// `amount * numerator / denominator`, rounded down; zero when the denominator is not positive
+    fn pro_rata(amount: i128, numerator: i128, denominator: i128) -> Result<i128, Error> {
+        if denominator <= 0 {
+            return Ok(0);
+        }
+
+        amount.checked_mul(numerator)
+            .map(|product| product / denominator)
+            .ok_or(Error::ArithmeticOverflow)
+    }
+
+    /// Slash the commitments of a window that were never revealed, returning the amount collected
+    fn forfeit_unrevealed(env: &Env, claim_id: u64, round: u32, window: u32) -> Result<i128, Error> {
+        let expert_registry = Self::expert_registry(env)?;
+        let this = env.current_contract_address();
+        let commitments = ReviewIndex::Commitments(claim_id, round);
+
+        let mut total_slashed: i128 = 0;
+        for position in 0..Self::get_index_size(env.clone(), commitments.clone()) {
+            let committer: Address = Self::index_get(env, &commitments, position);
+            let commitment_key = DataKey::Commitment(claim_id, committer.clone());
+            let mut commitment: Commitment = Self::read_persistent(env, &commitment_key)
+                .unwrap();
+
+            if commitment.window != window || commitment.revealed || commitment.settled {
+                continue;
+            }
+
+            // Experts who have since left the registry have no stake left to forfeit
+            if expert_registry.is_expert(&committer) {
+                expert_registry.unlock_stake(&this, &committer, &commitment.stake_amount);
+                let slashed = expert_registry.slash_stake(&this, &committer, &commitment.stake_amount);
+                total_slashed += slashed;
+
+                env.events().publish((symbol_short!("slashed"), claim_id), (committer.clone(), slashed));
+            }
+
+            commitment.settled = true;
+            Self::write_persistent(env, &commitment_key, &commitment);
+        }
+
+        Ok(total_slashed)
+    }
+
+    fn max_extensions(env: &Env) -> u32 {
+        env.storage().instance().get(&DataKey::MaxExtensions).unwrap_or(MAX_EXTENSIONS)
+    }
+
+    fn credit_treasury(env: &Env, amount: i128) {
+        let balance: i128 = env.storage().instance().get(&DataKey::TreasuryBalance).unwrap_or(0);
+        env.storage().instance().set(&DataKey::TreasuryBalance, &(balance + amount));
+        Self::extend_instance_ttl(env);
+    }
+
+    /// Latest finalized result of a claim; pending and lapsed appeal rounds leave the previous one standing
+    fn standing_consensus(env: &Env, claim_id: u64) -> Option<ConsensusResult> {
+        (0..=Self::current_round(env, claim_id))
+            .rev()
+            .find_map(|round| Self::read_persistent(env, &DataKey::ConsensusResult(claim_id, round)))
+    }
+
+    /// Whether a round has been finalized or, for an appeal, has lapsed
+    fn round_closed(env: &Env, claim_id: u64, round: u32) -> bool {
+        env.storage().persistent().has(&DataKey::ConsensusResult(claim_id, round))
+            || Self::read_persistent::<Appeal>(env, &DataKey::Appeal(claim_id, round))
+                .is_some_and(|appeal| appeal.status == AppealStatus::Lapsed)
+    }
+
+    /// Open a fresh review window for a claim's current round
+    fn open_review_window(env: &Env, claim_id: u64) -> ReviewWindow {
+        let commit_period: u64 = env.storage().instance().get(&DataKey::CommitPeriod).unwrap_or(COMMIT_PERIOD);
+        let reveal_period: u64 = env.storage().instance().get(&DataKey::RevealPeriod).unwrap_or(REVEAL_PERIOD);
 
-        let mut reviews = Vec::new(&env);
-        for review_id in review_ids.iter() {
-            if let Some(review) = env.storage().instance().get(&DataKey::Review(review_id)) {
+        let now = env.ledger().timestamp();
+        let window = ReviewWindow {
+            opens_at: now,
+            commit_deadline: now + commit_period,
+            reveal_deadline: now + commit_period + reveal_period,
+            extensions: 0,
+        };
+        Self::write_persistent(env, &DataKey::ReviewWindow(claim_id), &window);
+
+        window
+    }
+
+    /// Claim status recording a round's outcome
+    fn claim_status(outcome: &ConsensusOutcome) -> ClaimStatus {
+        match outcome {
+            ConsensusOutcome::Resolved(Verdict::True) => ClaimStatus::True,
+            ConsensusOutcome::Resolved(Verdict::False) => ClaimStatus::False,
+            ConsensusOutcome::Resolved(Verdict::Misleading) => ClaimStatus::Misleading,
+            ConsensusOutcome::Resolved(Verdict::Unverifiable) => ClaimStatus::Unverifiable,
+            ConsensusOutcome::Resolved(Verdict::PartiallyTrue) => ClaimStatus::PartiallyTrue,
+            ConsensusOutcome::Inconclusive => ClaimStatus::Inconclusive,
+        }
+    }
+
+    fn current_round(env: &Env, claim_id: u64) -> u32 {
+        Self::read_persistent(env, &DataKey::ClaimRound(claim_id)).unwrap_or(0)
+    }
+
+    /// Quorum of a claim's first round: its category's minimum, or the global default
+    fn min_reviews(env: &Env, claim_id: u64) -> Result<u32, Error> {
+        let claim_registry = Self::claim_registry(env)?;
+        let category = claim_registry.get_claim(&claim_id)
+            .and_then(|claim| claim_registry.get_category(&claim.category));
+
+        Ok(match category {
+            Some(category) => category.min_reviews,
+            None => env.storage().instance()
+                .get(&DataKey::MinReviewsForConsensus)
+                .unwrap_or(MIN_REVIEWS_FOR_CONSENSUS),
+        })
+    }
+
+    /// Rank a reviewer needs: the category's minimum level, raised for appeal rounds
+    fn required_rank(claim_registry: &ClaimRegistryClient, claim: &Claim, round: u32) -> u32 {
+        let round_rank = Self::level_rank(&Self::required_level(round));
+
+        match claim_registry.get_category(&claim.category) {
+            Some(category) => round_rank.max(Self::level_rank(&category.min_expert_level)),
+            None => round_rank,
+        }
+    }
+
+    fn require_eligible(
+        env: &Env,
+        profile: &Expert,
+        claim: &Claim,
+        required_rank: u32,
+    ) -> Result<(), Error> {
+        if Self::level_rank(&profile.expert_level) < required_rank {
+            return Err(Error::ExpertLevelTooLow);
+        }
+
+        let qualified = match Self::get_expertise_policy(env.clone()) {
+            ExpertisePolicy::Strict => profile.expertise_categories.contains(&claim.category),
+            ExpertisePolicy::MatchOrProfessional => {
+                profile.expert_level == ExpertLevel::Professional
+                    || profile.expertise_categories.contains(&claim.category)
+            }
+            ExpertisePolicy::Open => true,
+        };
+
+        if !qualified {
+            return Err(Error::ExpertiseMismatch);
+        }
+
+        Ok(())
+    }
+
+    /// Minimum expert level allowed to review a round
+    fn required_level(round: u32) -> ExpertLevel {
+        match round {
+            0 => ExpertLevel::General,
+            1 => ExpertLevel::Specialized,
+            _ => ExpertLevel::Professional,
+        }
+    }
+
+    fn level_rank(level: &ExpertLevel) -> u32 {
+        match level {
+            ExpertLevel::General => 0,
+            ExpertLevel::Specialized => 1,
+            ExpertLevel::Professional => 2,
+        }
+    }
+
+    fn review_weight(env: &Env, strategy: &ConsensusStrategy, review: &Review) -> Result<i128, Error> {
+        let weight = match strategy {
+            ConsensusStrategy::StakeOnly => review.stake_amount,
+            // Experts who have since left the registry count at the lowest level
+            ConsensusStrategy::ReputationWeighted => Self::expert_registry(env)?
+                .get_expert(&review.expert)
+                .map_or(1, |expert| Self::reputation_multiplier(&expert.reputation_level)),
+            ConsensusStrategy::ConfidenceWeighted => review.stake_amount * (review.confidence as i128) / 100,
+            ConsensusStrategy::QuadraticStake => Self::integer_sqrt(review.stake_amount),
+        };
+
+        Ok(weight)
+    }
+
+    fn reputation_multiplier(level: &ReputationLevel) -> i128 {
+        match level {
+            ReputationLevel::Seedling => 1,
+            ReputationLevel::Sprout => 2,
+            ReputationLevel::Established => 4,
+            ReputationLevel::Expert => 8,
+            ReputationLevel::Master => 16,
+        }
+    }
+
+    fn integer_sqrt(value: i128) -> i128 {
+        if value <= 0 {
+            return 0;
+        }
+
+        // Newton's method, converging from above
+        let mut x = value;
+        let mut y = (x + 1) / 2;
+        while y < x {
+            x = y;
+            y = (x + value / x) / 2;
+        }
+        x
+    }
+
+    fn read_persistent<V: TryFromVal<Env, Val>>(env: &Env, key: &DataKey) -> Option<V> {
+        let value: Option<V> = env.storage().persistent().get(key);
+        if value.is_some() {
+            env.storage().persistent().extend_ttl(key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+        }
+        value
+    }
+
+    fn write_persistent<V: IntoVal<Env, Val>>(env: &Env, key: &DataKey, value: &V) {
+        env.storage().persistent().set(key, value);
+        env.storage().persistent().extend_ttl(key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+        Self::extend_instance_ttl(env);
+    }
+
+    // Indexes store one entry per review or committer, so recording one touches a fixed number of entries
+    fn index_push<V: IntoVal<Env, Val>>(env: &Env, index: &ReviewIndex, value: &V) -> u32 {
+        let size = Self::get_index_size(env.clone(), index.clone());
+        Self::write_index_key(env, &DataKey::IndexEntry(index.clone(), size), value);
+        Self::write_index_key(env, &DataKey::IndexSize(index.clone()), &(size + 1));
+        size
+    }
+
+    fn write_index_key<V: IntoVal<Env, Val>>(env: &Env, key: &DataKey, value: &V) {
+        env.storage().persistent().set(key, value);
+        env.storage().persistent().extend_ttl(key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+    }
+
+    fn index_get<V: TryFromVal<Env, Val>>(env: &Env, index: &ReviewIndex, position: u32) -> V {
+        env.storage().persistent().get(&DataKey::IndexEntry(index.clone(), position)).unwrap()
+    }
+
+    fn read_review_page(env: &Env, index: &ReviewIndex, start: u32, limit: u32) -> Vec<Review> {
+        let mut reviews = Vec::new(env);
+
+        let end = Self::get_index_size(env.clone(), index.clone()).min(start.saturating_add(limit));
+        for position in start..end {
+            let review_id: u64 = Self::index_get(env, index, position);
+            if let Some(review) = Self::read_persistent(env, &DataKey::Review(review_id)) {
                 reviews.push_back(review);
             }
         }
 
         reviews
     }
+
+    fn extend_index_ttl(env: &Env, index: &ReviewIndex) {
+        for position in 0..Self::get_index_size(env.clone(), index.clone()) {
+            Self::extend_persistent_ttl(env, &DataKey::IndexEntry(index.clone(), position));
+        }
+        Self::extend_persistent_ttl(env, &DataKey::IndexSize(index.clone()));
+    }
+
+    fn extend_persistent_ttl(env: &Env, key: &DataKey) {
+        if env.storage().persistent().has(key) {
+            env.storage().persistent().extend_ttl(key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+        }
+    }
+
+    fn extend_instance_ttl(env: &Env) {
+        env.storage().instance().extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+    }
+
+    fn require_admin(env: &Env, admin: &Address) -> Result<(), Error> {
+        admin.require_auth();
+
+        let stored_admin: Address = env.storage().instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+
+        if *admin != stored_admin {
+            return Err(Error::NotAuthorized);
+        }
+
+        Ok(())
+    }
+
+    fn claim_registry(env: &Env) -> Result<ClaimRegistryClient<'_>, Error> {
+        let claim_contract: Address = env.storage().instance()
+            .get(&DataKey::ClaimRegistryContract)
+            .ok_or(Error::ClaimRegistryNotSet)?;
+        Ok(ClaimRegistryClient::new(env, &claim_contract))
+    }
+
+    fn expert_registry(env: &Env) -> Result<ExpertRegistryClient<'_>, Error> {
+        let expert_contract: Address = env.storage().instance()
+            .get(&DataKey::ExpertRegistryContract)
+            .ok_or(Error::ExpertRegistryNotSet)?;
+        Ok(ExpertRegistryClient::new(env, &expert_contract))
+    }
+
+    fn token_client(env: &Env) -> Result<token::Client<'_>, Error> {
+        let token_address: Address = env.storage().instance()
+            .get(&DataKey::Token)
+            .ok_or(Error::NotInitialized)?;
+        Ok(token::Client::new(env, &token_address))
+    }
 }
 
 #[cfg(test)]
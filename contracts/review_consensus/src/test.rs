@@ -1,7 +1,164 @@
 #![cfg(test)]
 
 use super::*;
-use soroban_sdk::{testutils::Address as _, Address, Env, String};
+use claim_registry::{ClaimRegistry, ClaimRegistryClient};
+use expert_registry::{ExpertRegistry, ExpertRegistryClient as ExpertRegistryContractClient};
+use soroban_sdk::{testutils::{storage::Persistent as _, Address as _, Ledger}, token::{StellarAssetClient, TokenClient}, xdr::ToXdr, Address, BytesN, Env, String, Vec};
+
+// Submission fee charged by the claim registry
+const CLAIM_FEE: i128 = 5_000_000;
+
+struct Setup<'a> {
+    env: Env,
+    admin: Address,
+    token_admin: StellarAssetClient<'a>,
+    token: TokenClient<'a>,
+    claims: ClaimRegistryClient<'a>,
+    experts: ExpertRegistryContractClient<'a>,
+    reviews: ReviewConsensusClient<'a>,
+}
+
+impl<'a> Setup<'a> {
+    /// Register and link all three contracts against a shared test token
+    fn new() -> Self {
+        let env = Env::default();
+        env.mock_all_auths();
+        // The test budget covers every call in a test, and some tests run several full review rounds
+        env.budget().reset_unlimited();
+
+        let admin = Address::generate(&env);
+        let token_address = env.register_stellar_asset_contract_v2(admin.clone()).address();
+
+        let claims = ClaimRegistryClient::new(&env, &env.register_contract(None, ClaimRegistry));
+        let experts = ExpertRegistryContractClient::new(&env, &env.register_contract(None, ExpertRegistry));
+        let reviews = ReviewConsensusClient::new(&env, &env.register_contract(None, ReviewConsensus));
+
+        claims.initialize(&admin, &token_address);
+        experts.initialize(&admin, &token_address);
+        reviews.initialize(&admin, &token_address);
+
+        claims.set_expert_registry(&admin, &experts.address);
+        claims.set_review_consensus(&admin, &reviews.address);
+        experts.set_review_consensus(&admin, &reviews.address);
+        reviews.set_claim_registry(&admin, &claims.address);
+        reviews.set_expert_registry(&admin, &experts.address);
+        experts.set_claim_registry(&admin, &claims.address);
+
+        let science = String::from_str(&env, "science");
+        let description = String::from_str(&env, "Natural sciences");
+        claims.create_category(&admin, &science, &science, &description, &CLAIM_FEE, &3, &claim_registry::ExpertLevel::General);
+
+        Setup {
+            token_admin: StellarAssetClient::new(&env, &token_address),
+            token: TokenClient::new(&env, &token_address),
+            env,
+            admin,
+            claims,
+            experts,
+            reviews,
+        }
+    }
+
+    fn expert(&self, stake: i128) -> Address {
+        self.expert_in(stake, Vec::new(&self.env))
+    }
+
+    fn expert_in(&self, stake: i128, expertise: Vec<String>) -> Address {
+        let expert = Address::generate(&self.env);
+        self.token_admin.mint(&expert, &stake);
+
+        let name = String::from_str(&self.env, "Reviewer");
+        let bio = String::from_str(&self.env, "Fact-checker");
+        self.experts.register_expert(&expert, &name, &bio, &expertise, &stake);
+
+        expert
+    }
+
+    fn panel<const N: usize>(&self, stake: i128) -> [Address; N] {
+        core::array::from_fn(|_| self.expert(stake))
+    }
+
+    fn claim(&self) -> u64 {
+        let submitter = Address::generate(&self.env);
+        self.token_admin.mint(&submitter, &10_000_000);
+
+        // Number each claim so the registry does not deduplicate them
+        let mut text = *b"Claim #00000";
+        let mut number = self.claims.get_claim_count() + 1;
+        for digit in text.iter_mut().rev().take(5) {
+            *digit = b'0' + (number % 10) as u8;
+            number /= 10;
+        }
+
+        let text = String::from_bytes(&self.env, &text);
+        let category = String::from_str(&self.env, "science");
+        self.claims.submit_claim(&submitter, &text, &category, &Vec::new(&self.env))
+    }
+
+    /// Deterministic per-expert salt
+    fn salt(&self, expert: &Address) -> BytesN<32> {
+        self.env.crypto().sha256(&expert.clone().to_xdr(&self.env)).into()
+    }
+
+    /// Commitment hash for the claim's current round
+    fn hash(&self, expert: &Address, claim_id: u64, verdict: &Verdict, confidence: u32) -> BytesN<32> {
+        let round = self.reviews.get_claim_round(&claim_id);
+        self.reviews.compute_commitment(expert, &claim_id, &round, verdict, &confidence, &self.salt(expert))
+    }
+
+    fn commit(&self, expert: &Address, claim_id: u64, verdict: &Verdict, confidence: u32, stake: i128) -> u64 {
+        let hash = self.hash(expert, claim_id, verdict, confidence);
+        self.reviews.commit_review(expert, &claim_id, &hash, &stake)
+    }
+
+    fn reveal(&self, expert: &Address, claim_id: u64, verdict: &Verdict, confidence: u32) -> u64 {
+        let reasoning = String::from_str(&self.env, "My analysis");
+        self.reviews.reveal_review(expert, &claim_id, verdict, &reasoning, &confidence, &self.salt(expert))
+    }
+
+    /// Commit every vote, close the commit phase and reveal them all
+    fn review_all(&self, claim_id: u64, votes: &[(&Address, Verdict, u32, i128)]) {
+        for (expert, verdict, confidence, stake) in votes {
+            self.commit(expert, claim_id, verdict, *confidence, *stake);
+        }
+        self.close_commit(claim_id);
+        for (expert, verdict, confidence, _) in votes {
+            self.reveal(expert, claim_id, verdict, *confidence);
+        }
+    }
+
+    fn close_commit(&self, claim_id: u64) {
+        let window = self.reviews.get_review_window(&claim_id).unwrap();
+        self.env.ledger().set_timestamp(window.commit_deadline);
+    }
+
+    fn close_reveal(&self, claim_id: u64) {
+        let window = self.reviews.get_review_window(&claim_id).unwrap();
+        self.env.ledger().set_timestamp(window.reveal_deadline);
+    }
+
+    /// Close the review window and compute consensus
+    fn finalize(&self, claim_id: u64) -> ConsensusResult {
+        self.close_reveal(claim_id);
+        self.reviews.finalize(&claim_id)
+    }
+
+    /// Wait out the appeal window and settle the claim in as many batches as it takes
+    fn settle(&self, claim_id: u64) {
+        let consensus = self.reviews.get_consensus(&claim_id).unwrap();
+        if self.env.ledger().timestamp() < consensus.appeal_deadline {
+            self.env.ledger().set_timestamp(consensus.appeal_deadline);
+        }
+        while !self.reviews.distribute_rewards(&claim_id) {}
+    }
+}
+
+/// Stake and weight behind a verdict in a consensus result
+fn tally(consensus: &ConsensusResult, verdict: Verdict) -> (i128, i128) {
+    consensus.tallies.iter()
+        .find(|tally| tally.verdict == verdict)
+        .map_or((0, 0), |tally| (tally.total_stake, tally.weight))
+}
 
 #[test]
 fn test_initialize() {
@@ -10,67 +167,1399 @@ fn test_initialize() {
     let client = ReviewConsensusClient::new(&env, &contract_id);
 
     let admin = Address::generate(&env);
-    client.initialize(&admin);
+    let token_address = env.register_stellar_asset_contract_v2(admin.clone()).address();
+    client.initialize(&admin, &token_address);
 
     assert_eq!(client.get_review_count(), 0);
-}
+    assert_eq!(client.try_initialize(&admin, &token_address), Err(Ok(Error::AlreadyInitialized)));
 
-#[test]
-fn test_submit_review() {
-    let env = Env::default();
+    // Reviews need both registries to be linked
     env.mock_all_auths();
-    
-    let contract_id = env.register_contract(None, ReviewConsensus);
-    let client = ReviewConsensusClient::new(&env, &contract_id);
-
-    let admin = Address::generate(&env);
     let expert = Address::generate(&env);
-    
-    client.initialize(&admin);
+    let hash = BytesN::from_array(&env, &[0; 32]);
+    assert_eq!(
+        client.try_commit_review(&expert, &1, &hash, &100_000_000),
+        Err(Ok(Error::ExpertRegistryNotSet))
+    );
+}
 
-    let claim_id = 1u64;
-    let reasoning = String::from_str(&env, "Evidence supports this claim");
+#[test]
+fn test_commit_and_reveal_review() {
+    let setup = Setup::new();
+    let env = &setup.env;
+
+    let claim_id = setup.claim();
+    let expert = setup.expert(1_000_000_000);
     let stake = 1_000_000_000i128; // 100 XLM
 
-    let review_id = client.submit_review(&expert, &claim_id, &Verdict::True, &reasoning, &95, &stake);
-    
+    // Amend the claim before anyone reviews it
+    let submitter = setup.claims.get_claim(&claim_id).unwrap().submitter;
+    let text = String::from_str(env, "The Earth is an oblate spheroid");
+    setup.claims.amend_claim(&submitter, &claim_id, &text, &Vec::new(env));
+
+    let commit_deadline = setup.commit(&expert, claim_id, &Verdict::True, 95, stake);
+    assert_eq!(commit_deadline, env.ledger().timestamp() + COMMIT_PERIOD);
+
+    // Commitment hides the verdict until reveal
+    let commitment = setup.reviews.get_commitment(&claim_id, &expert).unwrap();
+    assert!(!commitment.revealed);
+    assert_eq!(commitment.stake_amount, stake);
+    assert_eq!(commitment.claim_version, 2);
+    assert_eq!(setup.reviews.get_review_count(), 0);
+
+    // Claim moves under review and the review stake is locked
+    let claim = setup.claims.get_claim(&claim_id).unwrap();
+    assert_eq!(claim.review_count, 1);
+    assert_eq!(claim.status, claim_registry::ClaimStatus::UnderReview);
+    assert_eq!(setup.experts.get_expert(&expert).unwrap().locked_stake, stake);
+    assert_eq!(setup.experts.get_free_stake(&expert), 0);
+
+    setup.close_commit(claim_id);
+    let review_id = setup.reveal(&expert, claim_id, &Verdict::True, 95);
+
     assert_eq!(review_id, 1);
-    assert_eq!(client.get_review_count(), 1);
+    assert_eq!(setup.reviews.get_review_count(), 1);
+    assert!(setup.reviews.get_commitment(&claim_id, &expert).unwrap().revealed);
 
-    let review = client.get_review(&review_id).unwrap();
+    let review = setup.reviews.get_review(&review_id).unwrap();
     assert_eq!(review.verdict, Verdict::True);
     assert_eq!(review.confidence, 95);
+    assert_eq!(review.stake_amount, stake);
+    assert_eq!(review.claim_version, 2);
+
+    // Reviewed claims can no longer be amended
+    assert_eq!(
+        setup.claims.try_amend_claim(&submitter, &claim_id, &text, &Vec::new(env)),
+        Err(Ok(claim_registry::Error::ClaimUnderReview))
+    );
 }
 
 #[test]
-fn test_consensus_calculation() {
-    let env = Env::default();
-    env.mock_all_auths();
-    
-    let contract_id = env.register_contract(None, ReviewConsensus);
-    let client = ReviewConsensusClient::new(&env, &contract_id);
+fn test_commit_review_validation() {
+    let setup = Setup::new();
+    let env = &setup.env;
 
-    let admin = Address::generate(&env);
-    client.initialize(&admin);
+    let claim_id = setup.claim();
+    let expert = setup.expert(1_000_000_000);
+    let hash = setup.hash(&expert, claim_id, &Verdict::True, 90);
+
+    // Unregistered reviewer
+    let outsider = Address::generate(env);
+    assert_eq!(setup.reviews.try_commit_review(&outsider, &claim_id, &hash, &100_000_000), Err(Ok(Error::ExpertNotRegistered)));
+
+    // Nonexistent claim
+    assert_eq!(setup.reviews.try_commit_review(&expert, &42, &hash, &100_000_000), Err(Ok(Error::ClaimNotFound)));
+
+    // Stake beyond the expert's free stake, zero or negative
+    assert_eq!(setup.reviews.try_commit_review(&expert, &claim_id, &hash, &1_000_000_001), Err(Ok(Error::InsufficientFreeStake)));
+    assert_eq!(setup.reviews.try_commit_review(&expert, &claim_id, &hash, &0), Err(Ok(Error::InvalidStakeAmount)));
+    assert_eq!(setup.reviews.try_commit_review(&expert, &claim_id, &hash, &-1), Err(Ok(Error::InvalidStakeAmount)));
+
+    assert_eq!(setup.claims.get_claim(&claim_id).unwrap().review_count, 0);
+
+    // Stake locked in one review cannot back another
+    let other_claim = setup.claim();
+    setup.reviews.commit_review(&expert, &claim_id, &hash, &600_000_000);
+    assert_eq!(setup.reviews.try_commit_review(&expert, &other_claim, &hash, &600_000_000), Err(Ok(Error::InsufficientFreeStake)));
+    setup.reviews.commit_review(&expert, &other_claim, &hash, &400_000_000);
+
+    // One commitment per expert per claim
+    assert_eq!(setup.reviews.try_commit_review(&expert, &claim_id, &hash, &100_000_000), Err(Ok(Error::AlreadyReviewed)));
+
+    // Commitments are rejected once the commit phase has closed
+    setup.close_commit(claim_id);
+    let late = setup.expert(1_000_000_000);
+    assert_eq!(setup.reviews.try_commit_review(&late, &claim_id, &hash, &100_000_000), Err(Ok(Error::CommitPhaseClosed)));
+
+    // Rewards cannot be settled before consensus
+    assert_eq!(
+        setup.reviews.try_distribute_rewards(&claim_id),
+        Err(Ok(Error::ConsensusNotReached))
+    );
+}
+
+#[test]
+fn test_reveal_review_validation() {
+    let setup = Setup::new();
+    let env = &setup.env;
+
+    let claim_id = setup.claim();
+    let expert = setup.expert(1_000_000_000);
+    let reasoning = String::from_str(env, "My analysis");
+    let salt = setup.salt(&expert);
+
+    // Nothing to reveal without a commitment
+    assert_eq!(
+        setup.reviews.try_reveal_review(&expert, &claim_id, &Verdict::True, &reasoning, &90, &salt),
+        Err(Ok(Error::CommitmentNotFound))
+    );
+
+    setup.commit(&expert, claim_id, &Verdict::True, 90, 500_000_000);
+
+    // Reveals wait for the commit phase to close
+    assert_eq!(
+        setup.reviews.try_reveal_review(&expert, &claim_id, &Verdict::True, &reasoning, &90, &salt),
+        Err(Ok(Error::CommitPhaseOpen))
+    );
+
+    setup.close_commit(claim_id);
+
+    // The revealed vote must match the commitment exactly
+    let wrong_salt = BytesN::from_array(env, &[7; 32]);
+    assert_eq!(
+        setup.reviews.try_reveal_review(&expert, &claim_id, &Verdict::True, &reasoning, &90, &wrong_salt),
+        Err(Ok(Error::InvalidReveal))
+    );
+    assert_eq!(
+        setup.reviews.try_reveal_review(&expert, &claim_id, &Verdict::False, &reasoning, &90, &salt),
+        Err(Ok(Error::InvalidReveal))
+    );
+    assert_eq!(
+        setup.reviews.try_reveal_review(&expert, &claim_id, &Verdict::True, &reasoning, &101, &salt),
+        Err(Ok(Error::InvalidConfidence))
+    );
+
+    setup.reveal(&expert, claim_id, &Verdict::True, 90);
+    assert_eq!(
+        setup.reviews.try_reveal_review(&expert, &claim_id, &Verdict::True, &reasoning, &90, &salt),
+        Err(Ok(Error::AlreadyRevealed))
+    );
+
+    // Reveals are rejected once the reveal phase has closed
+    let other_claim = setup.claim();
+    let late = setup.expert(1_000_000_000);
+    setup.commit(&late, other_claim, &Verdict::True, 90, 100_000_000);
+    setup.close_reveal(other_claim);
+    assert_eq!(
+        setup.reviews.try_reveal_review(&late, &other_claim, &Verdict::True, &reasoning, &90, &setup.salt(&late)),
+        Err(Ok(Error::RevealPhaseClosed))
+    );
+}
+
+#[test]
+fn test_copied_commitment_cannot_be_revealed() {
+    let setup = Setup::new();
+    let env = &setup.env;
+
+    let claim_id = setup.claim();
+    let expert = setup.expert(1_000_000_000);
+    let copier = setup.expert(1_000_000_000);
+
+    // The copier commits the published hash of another expert's sealed vote
+    setup.commit(&expert, claim_id, &Verdict::False, 90, 500_000_000);
+    let copied = setup.reviews.get_commitment(&claim_id, &expert).unwrap().hash;
+    setup.reviews.commit_review(&copier, &claim_id, &copied, &500_000_000);
+
+    // Once the original is revealed, its salt does not open the copy
+    setup.close_commit(claim_id);
+    setup.reveal(&expert, claim_id, &Verdict::False, 90);
+
+    let reasoning = String::from_str(env, "Same as theirs");
+    assert_eq!(
+        setup.reviews.try_reveal_review(&copier, &claim_id, &Verdict::False, &reasoning, &90, &setup.salt(&expert)),
+        Err(Ok(Error::InvalidReveal))
+    );
+
+    // The hash is bound to the claim and round as well as the expert
+    let salt = setup.salt(&expert);
+    let hash = |claim_id: u64, round: u32| {
+        setup.reviews.compute_commitment(&expert, &claim_id, &round, &Verdict::False, &90, &salt)
+    };
+    assert_eq!(hash(claim_id, 0), copied);
+    assert_ne!(hash(claim_id + 1, 0), copied);
+    assert_ne!(hash(claim_id, 1), copied);
+}
+
+#[test]
+fn test_consensus_calculation() {
+    let setup = Setup::new();
 
-    let claim_id = 1u64;
-    let reasoning = String::from_str(&env, "My analysis");
+    let claim_id = setup.claim();
 
     // Three experts review - 2 vote True, 1 votes False
-    let expert1 = Address::generate(&env);
-    let expert2 = Address::generate(&env);
-    let expert3 = Address::generate(&env);
-
-    client.submit_review(&expert1, &claim_id, &Verdict::True, &reasoning, &90, &2_000_000_000i128);
-    client.submit_review(&expert2, &claim_id, &Verdict::True, &reasoning, &85, &1_500_000_000i128);
-    client.submit_review(&expert3, &claim_id, &Verdict::False, &reasoning, &70, &500_000_000i128);
-
-    // Consensus should be reached after 3 reviews
-    let consensus = client.get_consensus(&claim_id).unwrap();
-    assert_eq!(consensus.final_verdict, Verdict::True);
-    assert_eq!(consensus.is_finalized, true);
-    
+    let expert1 = setup.expert(2_000_000_000);
+    let expert2 = setup.expert(1_500_000_000);
+    let expert3 = setup.expert(1_000_000_000);
+
+    setup.review_all(claim_id, &[
+        (&expert1, Verdict::True, 90, 2_000_000_000),
+        (&expert2, Verdict::True, 85, 1_500_000_000),
+        (&expert3, Verdict::False, 70, 500_000_000),
+    ]);
+
+    // Consensus waits for the review window to close
+    assert!(setup.reviews.get_consensus(&claim_id).is_none());
+    let consensus = setup.finalize(claim_id);
+    assert_eq!(setup.reviews.get_consensus(&claim_id).unwrap().outcome, ConsensusOutcome::Resolved(Verdict::True));
+    assert_eq!(consensus.outcome, ConsensusOutcome::Resolved(Verdict::True));
+    assert!(consensus.is_finalized);
+
     // True stake: 3.5 billion, False stake: 0.5 billion
     // Confidence: 3.5 / 4.0 = 87.5% ≈ 87%
     assert!(consensus.confidence_percentage >= 85 && consensus.confidence_percentage <= 90);
+
+    // Verdict is propagated to the claim registry
+    let claim = setup.claims.get_claim(&claim_id).unwrap();
+    assert_eq!(claim.status, claim_registry::ClaimStatus::True);
+    assert_eq!(claim.review_count, 3);
+}
+
+#[test]
+fn test_false_verdict_closes_claim() {
+    let setup = Setup::new();
+
+    let claim_id = setup.claim();
+    let experts = [
+        setup.expert(1_000_000_000),
+        setup.expert(1_000_000_000),
+        setup.expert(1_000_000_000),
+    ];
+
+    setup.review_all(claim_id, &[
+        (&experts[0], Verdict::False, 80, 500_000_000),
+        (&experts[1], Verdict::False, 80, 500_000_000),
+        (&experts[2], Verdict::False, 80, 500_000_000),
+    ]);
+    setup.finalize(claim_id);
+
+    assert_eq!(setup.claims.get_claim(&claim_id).unwrap().status, claim_registry::ClaimStatus::False);
+
+    // Finalized claims no longer accept reviews
+    let late = setup.expert(1_000_000_000);
+    let hash = setup.hash(&late, claim_id, &Verdict::True, 80);
+    assert_eq!(setup.reviews.try_commit_review(&late, &claim_id, &hash, &100_000_000), Err(Ok(Error::AlreadyFinalized)));
+}
+
+#[test]
+fn test_finalize_after_review_window() {
+    let setup = Setup::new();
+    let env = &setup.env;
+
+    let claim_id = setup.claim();
+
+    // No window has been opened yet
+    assert_eq!(setup.reviews.try_finalize(&claim_id), Err(Ok(Error::InsufficientReviews)));
+
+    let experts = [
+        setup.expert(1_000_000_000),
+        setup.expert(1_000_000_000),
+        setup.expert(1_000_000_000),
+        setup.expert(1_000_000_000),
+    ];
+
+    env.ledger().set_timestamp(1_000);
+    setup.commit(&experts[0], claim_id, &Verdict::True, 90, 100_000_000);
+    let window = setup.reviews.get_review_window(&claim_id).unwrap();
+    assert_eq!(window.opens_at, 1_000);
+    assert_eq!(window.commit_deadline, 1_000 + COMMIT_PERIOD);
+    assert_eq!(window.reveal_deadline, 1_000 + COMMIT_PERIOD + REVEAL_PERIOD);
+
+    setup.commit(&experts[1], claim_id, &Verdict::True, 90, 100_000_000);
+    setup.commit(&experts[2], claim_id, &Verdict::False, 90, 100_000_000);
+    setup.commit(&experts[3], claim_id, &Verdict::False, 90, 500_000_000);
+
+    setup.close_commit(claim_id);
+    setup.reveal(&experts[0], claim_id, &Verdict::True, 90);
+    setup.reveal(&experts[1], claim_id, &Verdict::True, 90);
+    setup.reveal(&experts[2], claim_id, &Verdict::False, 90);
+
+    // Reaching the quorum does not finalize the claim before the window closes
+    assert!(setup.reviews.get_consensus(&claim_id).is_none());
+    assert_eq!(setup.reviews.try_finalize(&claim_id), Err(Ok(Error::RevealPhaseOpen)));
+
+    // The fourth reveal still counts and flips the outcome
+    setup.reveal(&experts[3], claim_id, &Verdict::False, 90);
+
+    let consensus = setup.finalize(claim_id);
+    assert_eq!(consensus.outcome, ConsensusOutcome::Resolved(Verdict::False));
+    assert_eq!(tally(&consensus, Verdict::False).0, 600_000_000);
+    assert_eq!(setup.claims.get_claim(&claim_id).unwrap().status, claim_registry::ClaimStatus::False);
+
+    assert_eq!(setup.reviews.try_finalize(&claim_id), Err(Ok(Error::AlreadyFinalized)));
+
+    // Claims without a quorum of revealed reviews cannot be finalized
+    let thin_claim = setup.claim();
+    setup.review_all(thin_claim, &[(&experts[0], Verdict::True, 90, 100_000_000)]);
+    setup.close_reveal(thin_claim);
+    assert_eq!(setup.reviews.try_finalize(&thin_claim), Err(Ok(Error::InsufficientReviews)));
+}
+
+#[test]
+fn test_consensus_strategies() {
+    let setup = Setup::new();
+
+    // One whale votes True against three Master-level experts voting False
+    let whale = setup.expert(4_000_000_000);
+    let masters = [
+        setup.expert(1_000_000_000),
+        setup.expert(1_000_000_000),
+        setup.expert(1_000_000_000),
+    ];
+
+    let curator = Address::generate(&setup.env);
+    setup.experts.add_authorized_caller(&setup.admin, &curator);
+    for master in masters.iter() {
+        setup.experts.update_reputation(&curator, master, &5_000, &true);
+    }
+
+    assert_eq!(setup.reviews.get_consensus_strategy(), ConsensusStrategy::StakeOnly);
+
+    let run = |strategy: ConsensusStrategy| {
+        let claim_id = setup.claim();
+        setup.review_all(claim_id, &[
+            (&whale, Verdict::True, 20, 800_000_000),
+            (&masters[0], Verdict::False, 90, 100_000_000),
+            (&masters[1], Verdict::False, 90, 100_000_000),
+            (&masters[2], Verdict::False, 90, 100_000_000),
+        ]);
+        setup.reviews.set_consensus_strategy(&setup.admin, &strategy);
+        setup.finalize(claim_id)
+    };
+
+    let stake_only = run(ConsensusStrategy::StakeOnly);
+    assert_eq!(stake_only.outcome, ConsensusOutcome::Resolved(Verdict::True));
+    assert_eq!(stake_only.strategy, ConsensusStrategy::StakeOnly);
+    assert_eq!(tally(&stake_only, Verdict::True), (800_000_000, 800_000_000));
+    assert_eq!(stake_only.confidence_percentage, 72);
+
+    let reputation = run(ConsensusStrategy::ReputationWeighted);
+    assert_eq!(reputation.outcome, ConsensusOutcome::Resolved(Verdict::False));
+    assert_eq!(reputation.strategy, ConsensusStrategy::ReputationWeighted);
+    assert_eq!(tally(&reputation, Verdict::True), (800_000_000, 1));
+    assert_eq!(tally(&reputation, Verdict::False), (300_000_000, 48));
+
+    let confidence = run(ConsensusStrategy::ConfidenceWeighted);
+    assert_eq!(confidence.outcome, ConsensusOutcome::Resolved(Verdict::False));
+    assert_eq!((tally(&confidence, Verdict::True).1, tally(&confidence, Verdict::False).1), (160_000_000, 270_000_000));
+
+    let quadratic = run(ConsensusStrategy::QuadraticStake);
+    assert_eq!(quadratic.outcome, ConsensusOutcome::Resolved(Verdict::False));
+    assert_eq!((tally(&quadratic, Verdict::True).1, tally(&quadratic, Verdict::False).1), (28_284, 30_000));
+
+    // Only the admin picks the strategy
+    let outsider = Address::generate(&setup.env);
+    assert_eq!(
+        setup.reviews.try_set_consensus_strategy(&outsider, &ConsensusStrategy::StakeOnly),
+        Err(Ok(Error::NotAuthorized))
+    );
+}
+
+#[test]
+fn test_tied_round_is_extended() {
+    let setup = Setup::new();
+
+    let claim_id = setup.claim();
+    let experts = [
+        setup.expert(1_000_000_000),
+        setup.expert(1_000_000_000),
+        setup.expert(1_000_000_000),
+        setup.expert(1_000_000_000),
+    ];
+
+    setup.review_all(claim_id, &[
+        (&experts[0], Verdict::True, 90, 100_000_000),
+        (&experts[1], Verdict::True, 90, 100_000_000),
+        (&experts[2], Verdict::False, 90, 100_000_000),
+        (&experts[3], Verdict::False, 90, 100_000_000),
+    ]);
+
+    // A tie is not finalized; the round is reopened instead
+    let tied = setup.finalize(claim_id);
+    assert_eq!(tied.outcome, ConsensusOutcome::Inconclusive);
+    assert!(!tied.is_finalized);
+    assert!(setup.reviews.get_consensus(&claim_id).is_none());
+    assert_eq!(setup.claims.get_claim(&claim_id).unwrap().status, claim_registry::ClaimStatus::UnderReview);
+
+    let window = setup.reviews.get_review_window(&claim_id).unwrap();
+    assert_eq!(window.extensions, 1);
+    assert_eq!(window.commit_deadline, setup.env.ledger().timestamp() + COMMIT_PERIOD);
+
+    // A fresh panel in the extended round decides it
+    let panel = setup.panel::<3>(1_000_000_000);
+    setup.review_all(claim_id, &[
+        (&panel[0], Verdict::True, 90, 100_000_000),
+        (&panel[1], Verdict::True, 90, 100_000_000),
+        (&panel[2], Verdict::True, 90, 100_000_000),
+    ]);
+
+    let consensus = setup.finalize(claim_id);
+    assert_eq!(consensus.outcome, ConsensusOutcome::Resolved(Verdict::True));
+    assert!(consensus.is_finalized);
+    assert_eq!(setup.claims.get_claim(&claim_id).unwrap().status, claim_registry::ClaimStatus::True);
+}
+
+#[test]
+fn test_extension_requires_new_commitments() {
+    let setup = Setup::new();
+
+    let claim_id = setup.claim();
+    let experts = setup.panel::<4>(1_000_000_000);
+    let silent = setup.expert(1_000_000_000);
+
+    setup.commit(&silent, claim_id, &Verdict::True, 90, 100_000_000);
+    setup.review_all(claim_id, &[
+        (&experts[0], Verdict::True, 90, 100_000_000),
+        (&experts[1], Verdict::True, 90, 100_000_000),
+        (&experts[2], Verdict::False, 90, 100_000_000),
+        (&experts[3], Verdict::False, 90, 100_000_000),
+    ]);
+
+    // Nobody can commit once the reveals have started
+    let late = setup.panel::<3>(1_000_000_000);
+    let hash = setup.hash(&late[0], claim_id, &Verdict::False, 90);
+    assert_eq!(
+        setup.reviews.try_commit_review(&late[0], &claim_id, &hash, &100_000_000),
+        Err(Ok(Error::CommitPhaseClosed))
+    );
+
+    // Extending the tied window forfeits the commitment that was never revealed
+    assert_eq!(setup.finalize(claim_id).outcome, ConsensusOutcome::Inconclusive);
+    let profile = setup.experts.get_expert(&silent).unwrap();
+    assert_eq!((profile.staked_amount, profile.locked_stake), (900_000_000, 0));
+    assert_eq!(setup.reviews.get_treasury_balance(), 100_000_000);
+    assert!(setup.reviews.get_commitment(&claim_id, &silent).unwrap().settled);
+
+    // Earlier reviewers cannot vote again, and the forfeited commitment cannot be revealed late
+    let hash = setup.hash(&experts[0], claim_id, &Verdict::False, 90);
+    assert_eq!(
+        setup.reviews.try_commit_review(&experts[0], &claim_id, &hash, &100_000_000),
+        Err(Ok(Error::AlreadyReviewed))
+    );
+
+    for (expert, verdict) in [(&late[0], Verdict::False), (&late[1], Verdict::False), (&late[2], Verdict::True)] {
+        setup.commit(expert, claim_id, &verdict, 90, 100_000_000);
+    }
+    setup.close_commit(claim_id);
+
+    let reasoning = String::from_str(&setup.env, "My analysis");
+    assert_eq!(
+        setup.reviews.try_reveal_review(&silent, &claim_id, &Verdict::True, &reasoning, &90, &setup.salt(&silent)),
+        Err(Ok(Error::CommitmentExpired))
+    );
+
+    for (expert, verdict) in [(&late[0], Verdict::False), (&late[1], Verdict::False), (&late[2], Verdict::True)] {
+        setup.reveal(expert, claim_id, &verdict, 90);
+    }
+
+    // Only the extension's own reviews are tallied
+    let consensus = setup.finalize(claim_id);
+    assert_eq!(consensus.outcome, ConsensusOutcome::Resolved(Verdict::False));
+    assert_eq!(tally(&consensus, Verdict::False).0, 200_000_000);
+    assert_eq!(tally(&consensus, Verdict::True).0, 100_000_000);
+
+    // Reviews from both windows are settled against the verdict
+    setup.settle(claim_id);
+    for expert in experts.iter().chain(late.iter()) {
+        assert_eq!(setup.experts.get_expert(expert).unwrap().locked_stake, 0);
+    }
+    assert_eq!(setup.experts.get_expert(&experts[0]).unwrap().staked_amount, 990_000_000);
+    assert_eq!(setup.experts.get_expert(&silent).unwrap().staked_amount, 900_000_000);
+}
+
+#[test]
+fn test_low_margin_is_inconclusive() {
+    let setup = Setup::new();
+
+    // Require a 70% supermajority with no extra rounds
+    assert_eq!(setup.reviews.try_set_supermajority(&setup.admin, &100, &0), Err(Ok(Error::InvalidThreshold)));
+    setup.reviews.set_supermajority(&setup.admin, &70, &0);
+
+    let claim_id = setup.claim();
+    let expert1 = setup.expert(1_000_000_000);
+    let expert2 = setup.expert(1_000_000_000);
+    let expert3 = setup.expert(1_000_000_000);
+
+    setup.review_all(claim_id, &[
+        (&expert1, Verdict::True, 90, 100_000_000),
+        (&expert2, Verdict::True, 90, 100_000_000),
+        (&expert3, Verdict::False, 90, 100_000_000),
+    ]);
+
+    let consensus = setup.finalize(claim_id);
+    assert_eq!(consensus.outcome, ConsensusOutcome::Inconclusive);
+    assert_eq!(consensus.confidence_percentage, 66);
+    assert!(consensus.is_finalized);
+    assert_eq!(setup.claims.get_claim(&claim_id).unwrap().status, claim_registry::ClaimStatus::Inconclusive);
+
+    // Inconclusive claims release review stakes without slashing or reputation changes
+    setup.settle(claim_id);
+    for expert in [&expert1, &expert2, &expert3] {
+        let profile = setup.experts.get_expert(expert).unwrap();
+        assert_eq!(profile.staked_amount, 1_000_000_000);
+        assert_eq!(profile.locked_stake, 0);
+        assert_eq!(profile.total_reviews, 0);
+    }
+
+    // With no verdict to reward, the claim's bounty is refunded to its submitter
+    let claim = setup.claims.get_claim(&claim_id).unwrap();
+    assert_eq!(claim.stake_pool, 0);
+    assert_eq!(setup.token.balance(&claim.submitter), 10_000_000);
+    assert_eq!(setup.reviews.get_claimable_rewards(&expert1), 0);
+    assert_eq!(setup.reviews.get_treasury_balance(), 0);
+}
+
+#[test]
+fn test_multi_outcome_plurality() {
+    let setup = Setup::new();
+
+    // Settle on the plurality verdict with no extra rounds
+    setup.reviews.set_supermajority(&setup.admin, &0, &0);
+
+    let claim_id = setup.claim();
+    let misleading1 = setup.expert(1_000_000_000);
+    let misleading2 = setup.expert(1_000_000_000);
+    let truthful = setup.expert(1_000_000_000);
+    let partial = setup.expert(1_000_000_000);
+    let unverifiable = setup.expert(1_000_000_000);
+
+    setup.review_all(claim_id, &[
+        (&misleading1, Verdict::Misleading, 90, 300_000_000),
+        (&truthful, Verdict::True, 90, 300_000_000),
+        (&misleading2, Verdict::Misleading, 90, 300_000_000),
+        (&partial, Verdict::PartiallyTrue, 90, 300_000_000),
+        (&unverifiable, Verdict::Unverifiable, 90, 300_000_000),
+    ]);
+
+    let consensus = setup.finalize(claim_id);
+    assert_eq!(consensus.outcome, ConsensusOutcome::Resolved(Verdict::Misleading));
+    assert_eq!(consensus.confidence_percentage, 40);
+    assert_eq!(consensus.tallies.len(), 4);
+    assert_eq!(tally(&consensus, Verdict::Misleading), (600_000_000, 600_000_000));
+    assert_eq!(tally(&consensus, Verdict::False), (0, 0));
+    assert_eq!(setup.claims.get_claim(&claim_id).unwrap().status, claim_registry::ClaimStatus::Misleading);
+
+    // Every losing bucket is slashed 10% and the pool is shared by the winners
+    setup.settle(claim_id);
+    for loser in [&truthful, &partial, &unverifiable] {
+        assert_eq!(setup.experts.get_expert(loser).unwrap().staked_amount, 970_000_000);
+    }
+    let reward = 300_000_000i128 * (72_000_000 + CLAIM_FEE) / 600_000_000;
+    assert_eq!(setup.reviews.get_claimable_rewards(&misleading1), reward);
+    assert_eq!(setup.reviews.get_claimable_rewards(&misleading2), reward);
+
+    // A tie between the leading verdicts is still inconclusive
+    let tied_claim = setup.claim();
+    setup.review_all(tied_claim, &[
+        (&misleading1, Verdict::Misleading, 90, 100_000_000),
+        (&truthful, Verdict::True, 90, 100_000_000),
+        (&partial, Verdict::PartiallyTrue, 90, 50_000_000),
+    ]);
+    assert_eq!(setup.finalize(tied_claim).outcome, ConsensusOutcome::Inconclusive);
+}
+
+#[test]
+fn test_appeal_flips_verdict() {
+    let setup = Setup::new();
+
+    let claim_id = setup.claim();
+    let generals = setup.panel::<3>(1_000_000_000);
+    setup.review_all(claim_id, &[
+        (&generals[0], Verdict::True, 90, 100_000_000),
+        (&generals[1], Verdict::True, 90, 100_000_000),
+        (&generals[2], Verdict::True, 90, 100_000_000),
+    ]);
+    setup.finalize(claim_id);
+
+    let appellant = Address::generate(&setup.env);
+    setup.token_admin.mint(&appellant, &MIN_APPEAL_BOND);
+    assert_eq!(setup.reviews.appeal(&claim_id, &appellant, &MIN_APPEAL_BOND), 1);
+    assert_eq!(setup.token.balance(&appellant), 0);
+
+    // The claim reopens while the original verdict still stands
+    assert_eq!(setup.reviews.get_claim_round(&claim_id), 1);
+    assert_eq!(setup.claims.get_claim(&claim_id).unwrap().status, claim_registry::ClaimStatus::UnderReview);
+    assert_eq!(setup.reviews.get_consensus(&claim_id).unwrap().round, 0);
+    assert_eq!(setup.reviews.get_appeal(&claim_id, &1).unwrap().status, AppealStatus::Pending);
+
+    // Appeal rounds need Specialized experts
+    let general = setup.expert(1_000_000_000);
+    let hash = setup.hash(&general, claim_id, &Verdict::False, 90);
+    assert_eq!(setup.reviews.try_commit_review(&general, &claim_id, &hash, &100_000_000), Err(Ok(Error::ExpertLevelTooLow)));
+
+    let panel = setup.panel::<6>(5_000_000_000);
+    setup.review_all(claim_id, &[
+        (&panel[0], Verdict::False, 90, 100_000_000),
+        (&panel[1], Verdict::False, 90, 100_000_000),
+        (&panel[2], Verdict::False, 90, 100_000_000),
+        (&panel[3], Verdict::False, 90, 100_000_000),
+        (&panel[4], Verdict::False, 90, 100_000_000),
+        (&panel[5], Verdict::True, 90, 100_000_000),
+    ]);
+
+    // Flipping the verdict refunds the bond
+    let consensus = setup.finalize(claim_id);
+    assert_eq!(consensus.round, 1);
+    assert_eq!(consensus.outcome, ConsensusOutcome::Resolved(Verdict::False));
+    assert_eq!(setup.claims.get_claim(&claim_id).unwrap().status, claim_registry::ClaimStatus::False);
+    assert_eq!(setup.reviews.get_appeal(&claim_id, &1).unwrap().status, AppealStatus::Upheld);
+    assert_eq!(setup.token.balance(&appellant), MIN_APPEAL_BOND);
+
+    let history = setup.reviews.get_consensus_history(&claim_id);
+    assert_eq!(history.len(), 2);
+    assert_eq!(history.get(0).unwrap().outcome, ConsensusOutcome::Resolved(Verdict::True));
+    assert_eq!(history.get(1).unwrap().outcome, ConsensusOutcome::Resolved(Verdict::False));
+
+    // Both rounds are settled against the final verdict
+    setup.settle(claim_id);
+    for expert in generals.iter().chain([&panel[5]]) {
+        assert_eq!(setup.experts.get_expert(expert).unwrap().correct_reviews, 0);
+    }
+    let reward = 100_000_000i128 * (32_000_000 + CLAIM_FEE) / 500_000_000;
+    assert_eq!(setup.reviews.get_claimable_rewards(&panel[0]), reward);
+}
+
+#[test]
+fn test_failed_appeal_forfeits_bond() {
+    let setup = Setup::new();
+
+    let generals = setup.panel::<3>(1_000_000_000);
+    let panel = setup.panel::<6>(5_000_000_000);
+    let appellant = Address::generate(&setup.env);
+    setup.token_admin.mint(&appellant, &(2 * MIN_APPEAL_BOND));
+
+    let claim_id = setup.claim();
+    assert_eq!(
+        setup.reviews.try_appeal(&claim_id, &appellant, &MIN_APPEAL_BOND),
+        Err(Ok(Error::ConsensusNotReached))
+    );
+
+    setup.review_all(claim_id, &[
+        (&generals[0], Verdict::True, 90, 100_000_000),
+        (&generals[1], Verdict::True, 90, 100_000_000),
+        (&generals[2], Verdict::True, 90, 100_000_000),
+    ]);
+    setup.finalize(claim_id);
+
+    assert_eq!(
+        setup.reviews.try_appeal(&claim_id, &appellant, &(MIN_APPEAL_BOND - 1)),
+        Err(Ok(Error::AppealBondTooLow))
+    );
+    setup.reviews.appeal(&claim_id, &appellant, &MIN_APPEAL_BOND);
+
+    // The appeal panel needs twice the quorum, so five reviews let the appeal lapse
+    setup.review_all(claim_id, &[
+        (&panel[0], Verdict::True, 90, 100_000_000),
+        (&panel[1], Verdict::True, 90, 100_000_000),
+        (&panel[2], Verdict::True, 90, 100_000_000),
+        (&panel[3], Verdict::True, 90, 100_000_000),
+        (&panel[4], Verdict::True, 90, 100_000_000),
+    ]);
+    setup.close_reveal(claim_id);
+    assert_eq!(setup.reviews.finalize(&claim_id).round, 0);
+    assert_eq!(setup.reviews.get_appeal(&claim_id, &1).unwrap().status, AppealStatus::Lapsed);
+
+    // Upholding the verdict forfeits the bond into the reward pool
+    let claim_id = setup.claim();
+    setup.review_all(claim_id, &[
+        (&generals[0], Verdict::True, 90, 100_000_000),
+        (&generals[1], Verdict::True, 90, 100_000_000),
+        (&generals[2], Verdict::True, 90, 100_000_000),
+    ]);
+    setup.finalize(claim_id);
+    setup.reviews.appeal(&claim_id, &appellant, &MIN_APPEAL_BOND);
+
+    setup.review_all(claim_id, &[
+        (&panel[0], Verdict::True, 90, 100_000_000),
+        (&panel[1], Verdict::True, 90, 100_000_000),
+        (&panel[2], Verdict::True, 90, 100_000_000),
+        (&panel[3], Verdict::True, 90, 100_000_000),
+        (&panel[4], Verdict::True, 90, 100_000_000),
+        (&panel[5], Verdict::True, 90, 100_000_000),
+    ]);
+    setup.finalize(claim_id);
+    assert_eq!(setup.reviews.get_appeal(&claim_id, &1).unwrap().status, AppealStatus::Rejected);
+    assert_eq!(setup.token.balance(&appellant), 0);
+
+    setup.settle(claim_id);
+    let reward = 100_000_000i128 * (MIN_APPEAL_BOND * 80 / 100 + CLAIM_FEE) / 900_000_000;
+    assert_eq!(setup.reviews.get_claimable_rewards(&generals[0]), reward);
+    assert_eq!(setup.reviews.get_claimable_rewards(&panel[5]), reward);
+
+    // Settled claims can no longer be appealed
+    assert_eq!(
+        setup.reviews.try_appeal(&claim_id, &appellant, &MIN_APPEAL_BOND),
+        Err(Ok(Error::AlreadySettled))
+    );
+}
+
+#[test]
+fn test_appeal_without_quorum_lapses() {
+    let setup = Setup::new();
+
+    let claim_id = setup.claim();
+    let generals = setup.panel::<3>(1_000_000_000);
+    setup.review_all(claim_id, &[
+        (&generals[0], Verdict::True, 90, 100_000_000),
+        (&generals[1], Verdict::True, 90, 100_000_000),
+        (&generals[2], Verdict::True, 90, 100_000_000),
+    ]);
+    setup.finalize(claim_id);
+
+    let appellant = Address::generate(&setup.env);
+    setup.token_admin.mint(&appellant, &MIN_APPEAL_BOND);
+    setup.reviews.appeal(&claim_id, &appellant, &MIN_APPEAL_BOND);
+
+    // The appeal round's window opens with the appeal, so it can lapse without a single commitment
+    assert!(setup.reviews.get_review_window(&claim_id).is_some());
+    assert_eq!(setup.reviews.try_finalize(&claim_id), Err(Ok(Error::RevealPhaseOpen)));
+    assert_eq!(setup.reviews.try_distribute_rewards(&claim_id), Err(Ok(Error::ConsensusNotReached)));
+
+    // One specialist commits and reveals, far short of the doubled quorum
+    let specialist = setup.expert(5_000_000_000);
+    setup.review_all(claim_id, &[(&specialist, Verdict::False, 90, 100_000_000)]);
+
+    // Lapsing restores the previous verdict and keeps the bond
+    let consensus = setup.finalize(claim_id);
+    assert_eq!(consensus.round, 0);
+    assert_eq!(consensus.outcome, ConsensusOutcome::Resolved(Verdict::True));
+    assert_eq!(setup.reviews.get_consensus(&claim_id).unwrap().round, 0);
+    assert_eq!(setup.claims.get_claim(&claim_id).unwrap().status, claim_registry::ClaimStatus::True);
+    assert_eq!(setup.reviews.get_appeal(&claim_id, &1).unwrap().status, AppealStatus::Lapsed);
+    assert_eq!(setup.token.balance(&appellant), 0);
+    assert_eq!(setup.reviews.try_finalize(&claim_id), Err(Ok(Error::AlreadyFinalized)));
+
+    // The forfeited bond joins the pool, and the lone appeal reviewer is settled against the restored verdict
+    setup.settle(claim_id);
+    let reward = 100_000_000i128 * ((MIN_APPEAL_BOND + 10_000_000) * 80 / 100 + CLAIM_FEE) / 300_000_000;
+    assert_eq!(setup.reviews.get_claimable_rewards(&generals[0]), reward);
+    assert_eq!(setup.experts.get_expert(&specialist).unwrap().staked_amount, 4_990_000_000);
+}
+
+#[test]
+fn test_inconclusive_appeal_forfeits_bond() {
+    let setup = Setup::new();
+    setup.reviews.set_supermajority(&setup.admin, &50, &0);
+
+    let claim_id = setup.claim();
+    let generals = setup.panel::<3>(1_000_000_000);
+    setup.review_all(claim_id, &[
+        (&generals[0], Verdict::True, 90, 100_000_000),
+        (&generals[1], Verdict::True, 90, 100_000_000),
+        (&generals[2], Verdict::True, 90, 100_000_000),
+    ]);
+    setup.finalize(claim_id);
+
+    let appellant = Address::generate(&setup.env);
+    setup.token_admin.mint(&appellant, &MIN_APPEAL_BOND);
+    setup.reviews.appeal(&claim_id, &appellant, &MIN_APPEAL_BOND);
+
+    let panel = setup.panel::<6>(5_000_000_000);
+    setup.review_all(claim_id, &[
+        (&panel[0], Verdict::False, 90, 100_000_000),
+        (&panel[1], Verdict::False, 90, 100_000_000),
+        (&panel[2], Verdict::False, 90, 100_000_000),
+        (&panel[3], Verdict::True, 90, 100_000_000),
+        (&panel[4], Verdict::True, 90, 100_000_000),
+        (&panel[5], Verdict::True, 90, 100_000_000),
+    ]);
+
+    // A deadlocked appeal changes the outcome but does not vindicate the appellant
+    let consensus = setup.finalize(claim_id);
+    assert_eq!(consensus.outcome, ConsensusOutcome::Inconclusive);
+    assert_eq!(setup.reviews.get_appeal(&claim_id, &1).unwrap().status, AppealStatus::Rejected);
+    assert_eq!(setup.token.balance(&appellant), 0);
+    assert_eq!(setup.token.balance(&setup.reviews.address), MIN_APPEAL_BOND);
+}
+
+#[test]
+fn test_claim_status_only_updatable_by_review_contract() {
+    let setup = Setup::new();
+
+    let claim_id = setup.claim();
+    let outsider = Address::generate(&setup.env);
+
+    let result = setup.claims.try_update_claim_status(&outsider, &claim_id, &claim_registry::ClaimStatus::True);
+    assert_eq!(result, Err(Ok(claim_registry::Error::NotAuthorized)));
+    assert_eq!(setup.claims.get_claim(&claim_id).unwrap().status, claim_registry::ClaimStatus::Pending);
+}
+
+#[test]
+fn test_distribute_rewards_end_to_end() {
+    let setup = Setup::new();
+
+    let claim_id = setup.claim();
+
+    let expert1 = setup.expert(2_000_000_000);
+    let expert2 = setup.expert(1_500_000_000);
+    let expert3 = setup.expert(1_000_000_000);
+
+    setup.review_all(claim_id, &[
+        (&expert1, Verdict::True, 90, 2_000_000_000),
+        (&expert2, Verdict::True, 85, 1_500_000_000),
+        (&expert3, Verdict::False, 70, 1_000_000_000),
+    ]);
+
+    // Settlement waits for the claim to be finalized
+    assert_eq!(
+        setup.reviews.try_distribute_rewards(&claim_id),
+        Err(Ok(Error::ConsensusNotReached))
+    );
+    let consensus = setup.finalize(claim_id);
+
+    // ...and for its appeal window to close, after which it can no longer be appealed
+    assert_eq!(setup.reviews.try_distribute_rewards(&claim_id), Err(Ok(Error::AppealWindowOpen)));
+    setup.env.ledger().set_timestamp(consensus.appeal_deadline);
+
+    let appellant = Address::generate(&setup.env);
+    setup.token_admin.mint(&appellant, &MIN_APPEAL_BOND);
+    assert_eq!(
+        setup.reviews.try_appeal(&claim_id, &appellant, &MIN_APPEAL_BOND),
+        Err(Ok(Error::AppealWindowClosed))
+    );
+    assert!(setup.reviews.distribute_rewards(&claim_id));
+
+    // Loser is slashed 10% of 100 XLM; winners share 80% of that plus the claim's bounty
+    let slashed = 100_000_000i128;
+    let reward1 = 2_000_000_000i128 * (80_000_000 + CLAIM_FEE) / 3_500_000_000;
+    let reward2 = 1_500_000_000i128 * (80_000_000 + CLAIM_FEE) / 3_500_000_000;
+
+    assert_eq!(setup.reviews.get_claimable_rewards(&expert1), reward1);
+    assert_eq!(setup.reviews.get_claimable_rewards(&expert2), reward2);
+    assert_eq!(setup.reviews.get_claimable_rewards(&expert3), 0);
+    assert_eq!(setup.token.balance(&setup.reviews.address), slashed + CLAIM_FEE);
+    assert_eq!(setup.token.balance(&setup.claims.address), 0);
+
+    // The treasury keeps the slashed stake and bounty not paid out, rounding dust included
+    assert_eq!(setup.reviews.get_treasury_balance(), CLAIM_FEE + slashed - reward1 - reward2);
+    assert_eq!(slashed * 20 / 100 + 1, CLAIM_FEE + slashed - reward1 - reward2);
+    assert_eq!(setup.claims.get_claim(&claim_id).unwrap().stake_pool, 0);
+    assert_eq!(setup.token.balance(&setup.experts.address), 4_500_000_000 - slashed);
+
+    let profile1 = setup.experts.get_expert(&expert1).unwrap();
+    assert_eq!(profile1.total_earnings, reward1);
+    assert_eq!(profile1.reputation_points, CORRECT_REVIEW_POINTS);
+    assert_eq!(profile1.correct_reviews, 1);
+
+    let profile3 = setup.experts.get_expert(&expert3).unwrap();
+    assert_eq!(profile3.staked_amount, 1_000_000_000 - slashed);
+    assert_eq!(profile3.reputation_points, 0);
+    assert_eq!(profile3.total_reviews, 1);
+    assert_eq!(profile3.correct_reviews, 0);
+
+    for review in setup.reviews.get_claim_reviews(&claim_id, &0, &10).iter() {
+        assert!(review.rewarded);
+    }
+
+    // Review stakes are released after settlement
+    assert_eq!(setup.experts.get_expert(&expert1).unwrap().locked_stake, 0);
+    assert_eq!(profile3.locked_stake, 0);
+
+    // Settling twice is a no-op
+    setup.settle(claim_id);
+    assert_eq!(setup.reviews.get_claimable_rewards(&expert1), reward1);
+    assert_eq!(setup.experts.get_expert(&expert3).unwrap().staked_amount, 1_000_000_000 - slashed);
+}
+
+#[test]
+fn test_settlement_runs_in_batches() {
+    let setup = Setup::new();
+
+    let claim_id = setup.claim();
+    let panel = setup.panel::<14>(1_000_000_000);
+    let silent = setup.expert(1_000_000_000);
+
+    // 15 commitments and 14 reviews take three batches of 20 to settle
+    setup.commit(&silent, claim_id, &Verdict::False, 90, 100_000_000);
+    let votes: [(&Address, Verdict, u32, i128); 14] = core::array::from_fn(|index| {
+        let verdict = if index < 10 { Verdict::True } else { Verdict::False };
+        (&panel[index], verdict, 90, 100_000_000)
+    });
+    setup.review_all(claim_id, &votes);
+
+    let consensus = setup.finalize(claim_id);
+    setup.env.ledger().set_timestamp(consensus.appeal_deadline);
+
+    assert!(!setup.reviews.distribute_rewards(&claim_id));
+    let settlement = setup.reviews.get_settlement(&claim_id).unwrap();
+    assert_eq!(settlement.phase, SettlementPhase::Slash);
+    assert_eq!(settlement.cursor, 5);
+
+    // The claim only counts as settled, and its bounty released, once every review is credited
+    assert!(!setup.reviews.distribute_rewards(&claim_id));
+    let settlement = setup.reviews.get_settlement(&claim_id).unwrap();
+    assert_eq!(settlement.phase, SettlementPhase::Credit);
+    assert_eq!(settlement.cursor, 11);
+    assert_eq!(settlement.bounty, CLAIM_FEE);
+    assert_eq!(setup.reviews.get_treasury_balance(), 0);
+
+    assert!(setup.reviews.distribute_rewards(&claim_id));
+    assert!(setup.reviews.get_settlement(&claim_id).is_none());
+
+    // The silent committer forfeits 10 XLM and the four losers 1 XLM each
+    let slashed = 100_000_000i128 + 4 * 10_000_000;
+    let reward = 100_000_000i128 * (slashed * 80 / 100 + CLAIM_FEE) / 1_000_000_000;
+    for expert in panel.iter().take(10) {
+        assert_eq!(setup.reviews.get_claimable_rewards(expert), reward);
+        assert_eq!(setup.experts.get_expert(expert).unwrap().locked_stake, 0);
+    }
+    assert_eq!(setup.reviews.get_treasury_balance(), slashed + CLAIM_FEE - 10 * reward);
+
+    // Settling twice is a no-op
+    assert!(setup.reviews.distribute_rewards(&claim_id));
+    assert_eq!(setup.reviews.get_claimable_rewards(&panel[0]), reward);
+}
+
+#[test]
+fn test_claim_rewards() {
+    let setup = Setup::new();
+
+    let expert1 = setup.expert(1_000_000_000);
+    let expert2 = setup.expert(1_000_000_000);
+    let expert3 = setup.expert(1_000_000_000);
+    assert_eq!(setup.reviews.try_claim_rewards(&expert1), Err(Ok(Error::NothingToClaim)));
+
+    // Rewards accrue across settled claims until withdrawn
+    let mut expected = 0;
+    for _ in 0..2 {
+        let claim_id = setup.claim();
+        setup.review_all(claim_id, &[
+            (&expert1, Verdict::True, 90, 300_000_000),
+            (&expert2, Verdict::True, 90, 300_000_000),
+            (&expert3, Verdict::False, 90, 300_000_000),
+        ]);
+        setup.finalize(claim_id);
+        setup.settle(claim_id);
+        expected += 300_000_000i128 * (24_000_000 + CLAIM_FEE) / 600_000_000;
+    }
+    assert_eq!(setup.reviews.get_claimable_rewards(&expert1), expected);
+    assert_eq!(setup.token.balance(&expert1), 0);
+
+    assert_eq!(setup.reviews.claim_rewards(&expert1), expected);
+    assert_eq!(setup.token.balance(&expert1), expected);
+    assert_eq!(setup.reviews.get_claimable_rewards(&expert1), 0);
+    assert_eq!(setup.reviews.try_claim_rewards(&expert1), Err(Ok(Error::NothingToClaim)));
+
+    // Other experts' balances are untouched
+    assert_eq!(setup.reviews.get_claimable_rewards(&expert2), expected);
+    assert_eq!(setup.token.balance(&setup.reviews.address), 2 * (30_000_000 + CLAIM_FEE) - expected);
+}
+
+#[test]
+fn test_unrevealed_commitment_forfeits_stake() {
+    let setup = Setup::new();
+
+    let claim_id = setup.claim();
+
+    let expert1 = setup.expert(1_000_000_000);
+    let expert2 = setup.expert(1_000_000_000);
+    let expert3 = setup.expert(1_000_000_000);
+    let silent = setup.expert(1_000_000_000);
+
+    setup.commit(&silent, claim_id, &Verdict::False, 90, 200_000_000);
+    setup.review_all(claim_id, &[
+        (&expert1, Verdict::True, 90, 500_000_000),
+        (&expert2, Verdict::True, 90, 500_000_000),
+        (&expert3, Verdict::True, 90, 500_000_000),
+    ]);
+
+    setup.finalize(claim_id);
+    setup.settle(claim_id);
+
+    // The silent committer loses the full committed stake, 80% of which goes to the winners
+    let profile = setup.experts.get_expert(&silent).unwrap();
+    assert_eq!(profile.staked_amount, 800_000_000);
+    assert_eq!(profile.locked_stake, 0);
+    assert!(setup.reviews.get_commitment(&claim_id, &silent).unwrap().settled);
+
+    let reward = 500_000_000i128 * (160_000_000 + CLAIM_FEE) / 1_500_000_000;
+    assert_eq!(setup.reviews.get_claimable_rewards(&expert1), reward);
+    assert_eq!(setup.token.balance(&setup.reviews.address), 200_000_000 + CLAIM_FEE);
+    assert_eq!(setup.reviews.get_treasury_balance(), 200_000_000 - 3 * reward + CLAIM_FEE);
+}
+
+#[test]
+fn test_settle_after_reviewers_leave() {
+    let setup = Setup::new();
+
+    let claim_id = setup.claim();
+
+    let [expert1, expert2, winner, loser] = setup.panel::<4>(1_000_000_000);
+    setup.review_all(claim_id, &[
+        (&expert1, Verdict::True, 90, 500_000_000),
+        (&expert2, Verdict::True, 90, 500_000_000),
+        (&winner, Verdict::True, 90, 500_000_000),
+        (&loser, Verdict::False, 90, 500_000_000),
+    ]);
+    setup.finalize(claim_id);
+
+    // Another authorized contract releases their stakes early, letting both fully exit the registry
+    let releaser = Address::generate(&setup.env);
+    setup.experts.add_authorized_caller(&setup.admin, &releaser);
+    for expert in [&winner, &loser] {
+        setup.experts.unlock_stake(&releaser, expert, &500_000_000);
+        setup.experts.request_unstake(expert, &1_000_000_000);
+    }
+    let request = setup.experts.get_unstake_request(&loser).unwrap();
+    setup.env.ledger().set_timestamp(request.available_at);
+    for expert in [&winner, &loser] {
+        setup.experts.withdraw_stake(expert);
+        assert!(!setup.experts.is_expert(expert));
+    }
+
+    // Settlement still completes: the departed loser has nothing left to slash, the departed winner is credited
+    setup.settle(claim_id);
+
+    let reward = 500_000_000i128 * CLAIM_FEE / 1_500_000_000;
+    assert_eq!(setup.reviews.get_claimable_rewards(&expert1), reward);
+    assert_eq!(setup.reviews.get_claimable_rewards(&winner), reward);
+    assert_eq!(setup.reviews.get_claimable_rewards(&loser), 0);
+    assert_eq!(setup.reviews.get_treasury_balance(), CLAIM_FEE - 3 * reward);
+
+    for review in setup.reviews.get_claim_reviews(&claim_id, &0, &10).iter() {
+        assert!(review.rewarded);
+    }
+
+    // The departed winner can still withdraw the credit
+    assert_eq!(setup.reviews.claim_rewards(&winner), reward);
+    assert_eq!(setup.token.balance(&winner), 1_000_000_000 + reward);
+}
+
+#[test]
+fn test_category_rules() {
+    let setup = Setup::new();
+
+    // Health claims need only two reviews, but from Specialized experts
+    let health = String::from_str(&setup.env, "health");
+    let description = String::from_str(&setup.env, "Medicine and public health");
+    setup.claims.create_category(&setup.admin, &health, &health, &description, &CLAIM_FEE, &2, &claim_registry::ExpertLevel::Specialized);
+
+    let submitter = Address::generate(&setup.env);
+    setup.token_admin.mint(&submitter, &CLAIM_FEE);
+    let text = String::from_str(&setup.env, "Vitamin C cures colds");
+    let claim_id = setup.claims.submit_claim(&submitter, &text, &health, &Vec::new(&setup.env));
+
+    let general = setup.expert(1_000_000_000);
+    let hash = setup.hash(&general, claim_id, &Verdict::False, 90);
+    assert_eq!(setup.reviews.try_commit_review(&general, &claim_id, &hash, &100_000_000), Err(Ok(Error::ExpertLevelTooLow)));
+
+    let specialists = setup.panel::<2>(5_000_000_000);
+    setup.review_all(claim_id, &[
+        (&specialists[0], Verdict::False, 90, 100_000_000),
+        (&specialists[1], Verdict::False, 90, 100_000_000),
+    ]);
+    assert_eq!(setup.finalize(claim_id).outcome, ConsensusOutcome::Resolved(Verdict::False));
+
+    // Other categories keep their own quorum
+    let claim_id = setup.claim();
+    setup.review_all(claim_id, &[
+        (&specialists[0], Verdict::True, 90, 100_000_000),
+        (&specialists[1], Verdict::True, 90, 100_000_000),
+    ]);
+    setup.close_reveal(claim_id);
+    assert_eq!(setup.reviews.try_finalize(&claim_id), Err(Ok(Error::InsufficientReviews)));
+}
+
+#[test]
+fn test_expertise_policy() {
+    let setup = Setup::new();
+    let env = &setup.env;
+
+    let health = String::from_str(env, "health");
+    let science = String::from_str(env, "science");
+    let description = String::from_str(env, "Medicine and public health");
+    setup.claims.create_category(&setup.admin, &health, &health, &description, &CLAIM_FEE, &3, &claim_registry::ExpertLevel::General);
+
+    let doctor = setup.expert_in(1_000_000_000, Vec::from_array(env, [health.clone()]));
+    let physicist = setup.expert_in(1_000_000_000, Vec::from_array(env, [science.clone()]));
+    let professional = setup.expert(10_000_000_000);
+
+    let submitter = Address::generate(env);
+    setup.token_admin.mint(&submitter, &CLAIM_FEE);
+    let text = String::from_str(env, "Vitamin C cures colds");
+    let claim_id = setup.claims.submit_claim(&submitter, &text, &health, &Vec::new(env));
+
+    // Anyone may review by default
+    assert_eq!(setup.reviews.get_expertise_policy(), ExpertisePolicy::Open);
+    assert_eq!(setup.reviews.get_eligible_reviewers(&claim_id, &0, &10).len(), 3);
+
+    // Strict matching admits only the category's experts
+    setup.reviews.set_expertise_policy(&setup.admin, &ExpertisePolicy::Strict);
+    assert_eq!(setup.reviews.get_eligible_reviewers(&claim_id, &0, &10), Vec::from_array(env, [doctor.clone()]));
+    for outsider in [&physicist, &professional] {
+        let hash = setup.hash(outsider, claim_id, &Verdict::False, 90);
+        assert_eq!(setup.reviews.try_commit_review(outsider, &claim_id, &hash, &100_000_000), Err(Ok(Error::ExpertiseMismatch)));
+    }
+
+    // Professionals may also review outside their expertise
+    setup.reviews.set_expertise_policy(&setup.admin, &ExpertisePolicy::MatchOrProfessional);
+    assert_eq!(
+        setup.reviews.get_eligible_reviewers(&claim_id, &0, &10),
+        Vec::from_array(env, [doctor.clone(), professional.clone()])
+    );
+    let hash = setup.hash(&physicist, claim_id, &Verdict::False, 90);
+    assert_eq!(setup.reviews.try_commit_review(&physicist, &claim_id, &hash, &100_000_000), Err(Ok(Error::ExpertiseMismatch)));
+
+    // Pages cover the registry's candidates, so the physicist's page comes back empty
+    assert_eq!(setup.reviews.get_eligible_reviewers(&claim_id, &0, &1), Vec::from_array(env, [doctor.clone()]));
+    assert_eq!(setup.reviews.get_eligible_reviewers(&claim_id, &1, &1).len(), 0);
+    assert_eq!(setup.reviews.get_eligible_reviewers(&claim_id, &2, &1), Vec::from_array(env, [professional.clone()]));
+    setup.commit(&professional, claim_id, &Verdict::False, 90, 100_000_000);
+    setup.commit(&doctor, claim_id, &Verdict::False, 90, 100_000_000);
+
+    // Experts who already committed drop off the list
+    assert_eq!(setup.reviews.get_eligible_reviewers(&claim_id, &0, &10).len(), 0);
+    assert_eq!(setup.reviews.try_get_eligible_reviewers(&99, &0, &10), Err(Ok(Error::ClaimNotFound)));
+}
+
+#[test]
+fn test_expire_unresolved_claim() {
+    let setup = Setup::new();
+
+    let claim_id = setup.claim();
+    let reviewed_claim = setup.claim();
+    let idle_claim = setup.claim();
+
+    let sponsor = Address::generate(&setup.env);
+    setup.token_admin.mint(&sponsor, &50_000_000);
+    setup.claims.add_to_stake_pool(&sponsor, &claim_id, &50_000_000);
+
+    let reviewers = setup.panel::<3>(1_000_000_000);
+    let silent = setup.expert(1_000_000_000);
+
+    // Two reveals fall short of the quorum and the third reviewer never reveals
+    setup.commit(&silent, claim_id, &Verdict::False, 90, 200_000_000);
+    setup.review_all(claim_id, &[
+        (&reviewers[0], Verdict::True, 90, 100_000_000),
+        (&reviewers[1], Verdict::True, 90, 100_000_000),
+    ]);
+    assert_eq!(setup.reviews.try_expire_claim(&claim_id), Err(Ok(Error::ClaimNotExpired)));
+
+    setup.close_reveal(claim_id);
+    assert_eq!(setup.reviews.try_finalize(&claim_id), Err(Ok(Error::InsufficientReviews)));
+    assert_eq!(setup.reviews.try_expire_claim(&claim_id), Err(Ok(Error::ClaimNotExpired)));
+
+    setup.review_all(reviewed_claim, &[
+        (&reviewers[0], Verdict::True, 90, 100_000_000),
+        (&reviewers[1], Verdict::True, 90, 100_000_000),
+        (&reviewers[2], Verdict::True, 90, 100_000_000),
+    ]);
+
+    // Past the expiry, revealed stakes are released and sponsors refunded in full
+    setup.env.ledger().set_timestamp(CLAIM_EXPIRY);
+    assert_eq!(setup.reviews.expire_claim(&claim_id), CLAIM_FEE + 50_000_000);
+    assert_eq!(setup.token.balance(&sponsor), 50_000_000);
+    assert_eq!(setup.claims.get_claim(&claim_id).unwrap().status, claim_registry::ClaimStatus::Expired);
+
+    for expert in [&reviewers[0], &reviewers[1]] {
+        let profile = setup.experts.get_expert(expert).unwrap();
+        assert_eq!(profile.staked_amount, 1_000_000_000);
+        assert_eq!(profile.reputation_points, 0);
+    }
+
+    // The silent committer still forfeits the unrevealed stake, to the treasury
+    let profile = setup.experts.get_expert(&silent).unwrap();
+    assert_eq!(profile.staked_amount, 800_000_000);
+    assert_eq!(profile.locked_stake, 0);
+    assert!(setup.reviews.get_commitment(&claim_id, &silent).unwrap().settled);
+    assert_eq!(setup.reviews.get_treasury_balance(), 200_000_000);
+
+    assert_eq!(setup.reviews.try_expire_claim(&claim_id), Err(Ok(Error::ClaimNotOpen)));
+    let hash = setup.hash(&reviewers[2], claim_id, &Verdict::True, 90);
+    assert_eq!(setup.reviews.try_commit_review(&reviewers[2], &claim_id, &hash, &100_000_000), Err(Ok(Error::ClaimNotOpen)));
+
+    // Claims that can still be finalized do not expire
+    assert_eq!(setup.reviews.try_expire_claim(&reviewed_claim), Err(Ok(Error::QuorumReached)));
+    setup.reviews.finalize(&reviewed_claim);
+    assert_eq!(setup.reviews.try_expire_claim(&reviewed_claim), Err(Ok(Error::AlreadyFinalized)));
+
+    // Claims nobody reviewed expire too
+    assert_eq!(setup.reviews.expire_claim(&idle_claim), CLAIM_FEE);
+    assert_eq!(setup.token.balance(&setup.claims.address), CLAIM_FEE); // Held for the finalized claim's settlement
+}
+
+#[test]
+fn test_zero_stake_review_rejected() {
+    let setup = Setup::new();
+
+    let claim_id = setup.claim();
+    let expert = setup.expert(1_000_000_000);
+
+    // A stake-free review would leave nothing locked, letting its expert leave before settlement
+    let hash = setup.hash(&expert, claim_id, &Verdict::True, 90);
+    assert_eq!(setup.reviews.try_commit_review(&expert, &claim_id, &hash, &0), Err(Ok(Error::InvalidStakeAmount)));
+
+    // Even the smallest stake keeps the expert registered until the claim is settled
+    setup.commit(&expert, claim_id, &Verdict::True, 90, 1);
+    assert_eq!(
+        setup.experts.try_request_unstake(&expert, &1_000_000_000),
+        Err(Ok(expert_registry::Error::StakeLocked))
+    );
+}
+
+#[test]
+fn test_reward_split_never_exceeds_pool() {
+    // Deterministic pseudo-random cases
+    let mut seed: u64 = 0x2545_f491_4f6c_dd1d;
+    let mut next = |bound: u64| {
+        seed ^= seed << 13;
+        seed ^= seed >> 7;
+        seed ^= seed << 17;
+        seed % bound
+    };
+
+    for _ in 0..1_000 {
+        let winners = 1 + next(10) as usize;
+        let mut stakes = [0i128; 10];
+        for stake in stakes.iter_mut().take(winners) {
+            // Mix of zero, tiny and large stakes
+            *stake = match next(4) {
+                0 => 0,
+                1 => next(10) as i128,
+                _ => next(100_000_000_000_000) as i128,
+            };
+        }
+        let total_stake: i128 = stakes.iter().sum();
+        let pool = next(1_000_000_000_000_000) as i128;
+
+        let mut credited = 0;
+        for stake in stakes.iter().take(winners) {
+            let share = ReviewConsensus::pro_rata(pool, *stake, total_stake).unwrap();
+            assert!(share >= 0);
+            credited += share;
+        }
+
+        assert!(credited <= pool);
+        if total_stake > 0 {
+            // Rounding loses less than one stroop per winner
+            assert!(pool - credited < winners as i128);
+        } else {
+            assert_eq!(credited, 0);
+        }
+    }
+
+    assert_eq!(ReviewConsensus::pro_rata(i128::MAX, 2, 3), Err(Error::ArithmeticOverflow));
+
+    // A real settlement whose winning stakes do not divide the pool evenly
+    let setup = Setup::new();
+    let claim_id = setup.claim();
+    let winners = setup.panel::<3>(1_000_000_000);
+    let losers = setup.panel::<2>(1_000_000_000);
+
+    setup.review_all(claim_id, &[
+        (&winners[0], Verdict::True, 90, 100_000_001),
+        (&winners[1], Verdict::True, 90, 200_000_003),
+        (&winners[2], Verdict::True, 90, 333_333_337),
+        (&losers[0], Verdict::False, 90, 123_456_789),
+        (&losers[1], Verdict::False, 90, 98_765_431),
+    ]);
+    setup.finalize(claim_id);
+
+    let bounty = setup.claims.get_claim(&claim_id).unwrap().stake_pool;
+    let treasury_before = setup.reviews.get_treasury_balance();
+    setup.settle(claim_id);
+
+    let slashed: i128 = losers.iter()
+        .map(|loser| 1_000_000_000 - setup.experts.get_expert(loser).unwrap().staked_amount)
+        .sum();
+    let credited: i128 = winners.iter()
+        .map(|winner| setup.reviews.get_claimable_rewards(winner))
+        .sum();
+    let treasury = setup.reviews.get_treasury_balance() - treasury_before;
+    assert_eq!(slashed, 12_345_678 + 9_876_543);
+    assert!(credited > 0 && treasury > 0);
+
+    // Every slashed stroop and the whole bounty end up either credited or in the treasury
+    assert_eq!(credited + treasury, slashed + bounty);
+
+    // The contract holds enough to pay out every credit and the treasury
+    let owed = credited + setup.reviews.get_treasury_balance();
+    assert!(setup.token.balance(&setup.reviews.address) >= owed);
+    for winner in winners.iter() {
+        setup.reviews.claim_rewards(winner);
+    }
+    setup.reviews.withdraw_treasury(&setup.admin, &setup.admin, &setup.reviews.get_treasury_balance());
+    assert_eq!(setup.reviews.get_treasury_balance(), 0);
+}
+
+#[test]
+fn test_treasury() {
+    let setup = Setup::new();
+
+    // Inconclusive claims send everything forfeited to the treasury
+    setup.reviews.set_supermajority(&setup.admin, &50, &0);
+    let claim_id = setup.claim();
+    let experts = setup.panel::<4>(1_000_000_000);
+    let silent = setup.expert(1_000_000_000);
+
+    setup.commit(&silent, claim_id, &Verdict::True, 90, 100_000_000);
+    setup.review_all(claim_id, &[
+        (&experts[0], Verdict::True, 90, 100_000_000),
+        (&experts[1], Verdict::True, 90, 100_000_000),
+        (&experts[2], Verdict::False, 90, 100_000_000),
+        (&experts[3], Verdict::False, 90, 100_000_000),
+    ]);
+    assert_eq!(setup.finalize(claim_id).outcome, ConsensusOutcome::Inconclusive);
+    setup.settle(claim_id);
+
+    // The bounty itself goes back to the submitter
+    let balance = 100_000_000;
+    assert_eq!(setup.token.balance(&setup.claims.get_claim(&claim_id).unwrap().submitter), 10_000_000);
+    assert_eq!(setup.reviews.get_treasury_balance(), balance);
+    assert_eq!(setup.reviews.get_claimable_rewards(&experts[0]), 0);
+
+    // Only the admin withdraws, and never more than the treasury holds
+    let recipient = Address::generate(&setup.env);
+    assert_eq!(
+        setup.reviews.try_withdraw_treasury(&recipient, &recipient, &1),
+        Err(Ok(Error::NotAuthorized))
+    );
+    assert_eq!(
+        setup.reviews.try_withdraw_treasury(&setup.admin, &recipient, &(balance + 1)),
+        Err(Ok(Error::InsufficientTreasury))
+    );
+    assert_eq!(
+        setup.reviews.try_withdraw_treasury(&setup.admin, &recipient, &0),
+        Err(Ok(Error::InvalidAmount))
+    );
+
+    setup.reviews.withdraw_treasury(&setup.admin, &recipient, &balance);
+    assert_eq!(setup.token.balance(&recipient), balance);
+    assert_eq!(setup.reviews.get_treasury_balance(), 0);
+}
+
+#[test]
+fn test_review_records_use_persistent_storage() {
+    let setup = Setup::new();
+    let env = &setup.env;
+
+    let claim_id = setup.claim();
+    let experts = setup.panel::<3>(1_000_000_000);
+    let expert = experts[0].clone();
+    setup.review_all(claim_id, &[
+        (&experts[0], Verdict::True, 90, 100_000_000),
+        (&experts[1], Verdict::True, 90, 100_000_000),
+        (&experts[2], Verdict::True, 90, 100_000_000),
+    ]);
+    let review_id = 1;
+
+    let ttl = |key: DataKey| env.as_contract(&setup.reviews.address, || env.storage().persistent().get_ttl(&key));
+    assert_eq!(ttl(DataKey::Review(review_id)), PERSISTENT_BUMP_AMOUNT);
+    assert_eq!(ttl(DataKey::IndexSize(ReviewIndex::Claim(claim_id))), PERSISTENT_BUMP_AMOUNT);
+    assert_eq!(ttl(DataKey::IndexEntry(ReviewIndex::Claim(claim_id), 0)), PERSISTENT_BUMP_AMOUNT);
+    assert_eq!(ttl(DataKey::IndexEntry(ReviewIndex::Expert(expert.clone()), 0)), PERSISTENT_BUMP_AMOUNT);
+    assert_eq!(ttl(DataKey::Commitment(claim_id, expert.clone())), PERSISTENT_BUMP_AMOUNT);
+
+    // Each index holds one entry per review or committer
+    assert_eq!(setup.reviews.get_index_size(&ReviewIndex::Claim(claim_id)), 3);
+    assert_eq!(setup.reviews.get_index_size(&ReviewIndex::Window(claim_id, 0, 0)), 3);
+    assert_eq!(setup.reviews.get_index_size(&ReviewIndex::Commitments(claim_id, 0)), 3);
+    assert_eq!(setup.reviews.get_expert_reviews(&expert, &0, &10).get_unchecked(0).id, review_id);
+    assert!(setup.reviews.get_expert_reviews(&expert, &1, &10).is_empty());
+    assert_eq!(setup.reviews.get_claim_reviews(&claim_id, &1, &10).len(), 2);
+
+    setup.finalize(claim_id);
+    setup.settle(claim_id);
+    assert!(setup.reviews.get_claimable_rewards(&expert) > 0);
+
+    // Bumping a claim also reaches its reviewers' review indexes and claimable rewards
+    env.ledger().with_mut(|li| li.sequence_number += 5 * DAY_IN_LEDGERS);
+    let mut claim_ids = Vec::new(env);
+    claim_ids.push_back(claim_id);
+    setup.reviews.bump_ttl(&setup.admin, &claim_ids);
+
+    assert_eq!(ttl(DataKey::Review(review_id)), PERSISTENT_BUMP_AMOUNT);
+    assert_eq!(ttl(DataKey::IndexEntry(ReviewIndex::Claim(claim_id), 0)), PERSISTENT_BUMP_AMOUNT);
+    assert_eq!(ttl(DataKey::IndexEntry(ReviewIndex::Expert(expert.clone()), 0)), PERSISTENT_BUMP_AMOUNT);
+    assert_eq!(ttl(DataKey::IndexSize(ReviewIndex::Expert(expert.clone()))), PERSISTENT_BUMP_AMOUNT);
+    assert_eq!(ttl(DataKey::ClaimableRewards(expert)), PERSISTENT_BUMP_AMOUNT);
 }
@@ -62,6 +62,10 @@ echo "✅ Review Consensus deployed: $REVIEW_CONSENSUS_ID"
 echo ""
 echo "⚙️  Initializing contracts..."
 
+# Native XLM Stellar Asset Contract used for fees and stakes
+NATIVE_TOKEN_ID=$(stellar contract id asset --asset native --network $NETWORK)
+echo "Native token contract: $NATIVE_TOKEN_ID"
+
 # Initialize Claim Registry
 echo "Initializing Claim Registry..."
 stellar contract invoke \
@@ -69,7 +73,8 @@ stellar contract invoke \
     --source deployer \
     --network $NETWORK \
     -- initialize \
-    --admin $DEPLOYER_ADDRESS
+    --admin $DEPLOYER_ADDRESS \
+    --token $NATIVE_TOKEN_ID
 
 # Initialize Expert Registry
 echo "Initializing Expert Registry..."
@@ -78,7 +83,8 @@ stellar contract invoke \
     --source deployer \
     --network $NETWORK \
     -- initialize \
-    --admin $DEPLOYER_ADDRESS
+    --admin $DEPLOYER_ADDRESS \
+    --token $NATIVE_TOKEN_ID
 
 # Initialize Review Consensus
 echo "Initializing Review Consensus..."
@@ -87,7 +93,8 @@ stellar contract invoke \
     --source deployer \
     --network $NETWORK \
     -- initialize \
-    --admin $DEPLOYER_ADDRESS
+    --admin $DEPLOYER_ADDRESS \
+    --token $NATIVE_TOKEN_ID
 
 # Link contracts together
 echo ""
@@ -111,6 +118,15 @@ stellar contract invoke \
     --admin $DEPLOYER_ADDRESS \
     --review_contract $REVIEW_CONSENSUS_ID
 
+echo "Setting Review Consensus in Expert Registry..."
+stellar contract invoke \
+    --id $EXPERT_REGISTRY_ID \
+    --source deployer \
+    --network $NETWORK \
+    -- set_review_consensus \
+    --admin $DEPLOYER_ADDRESS \
+    --review_contract $REVIEW_CONSENSUS_ID
+
 echo "Setting Claim Registry in Review Consensus..."
 stellar contract invoke \
     --id $REVIEW_CONSENSUS_ID \
@@ -129,6 +145,38 @@ stellar contract invoke \
     --admin $DEPLOYER_ADDRESS \
     --expert_contract $EXPERT_REGISTRY_ID
 
+echo "Setting Claim Registry in Expert Registry..."
+stellar contract invoke \
+    --id $EXPERT_REGISTRY_ID \
+    --source deployer \
+    --network $NETWORK \
+    -- set_claim_registry \
+    --admin $DEPLOYER_ADDRESS \
+    --claim_contract $CLAIM_REGISTRY_ID
+
+# Seed the claim categories (0.5 XLM fee, 3 reviews, any expert level)
+echo ""
+echo "🗂️  Creating categories..."
+
+for CATEGORY in "science:Science" "politics:Politics" "health:Health" "technology:Technology" \
+    "environment:Environment" "economics:Economics" "other:Other"; do
+    SLUG=${CATEGORY%%:*}
+    NAME=${CATEGORY#*:}
+    echo "Creating category $SLUG..."
+    stellar contract invoke \
+        --id $CLAIM_REGISTRY_ID \
+        --source deployer \
+        --network $NETWORK \
+        -- create_category \
+        --admin $DEPLOYER_ADDRESS \
+        --slug "$SLUG" \
+        --name "$NAME" \
+        --description "$NAME claims" \
+        --claim_fee 5000000 \
+        --min_reviews 3 \
+        --min_expert_level General
+done
+
 echo ""
 echo "✅ All contracts deployed and initialized successfully!"
 echo ""
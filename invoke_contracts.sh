@@ -95,19 +95,49 @@ case "$1" in
       get_expert_count
     ;;
 
-  "submit-review")
-    echo "⭐ Submitting review..."
+  "commit-review")
+    # The salt must stay secret until the reveal; keep it together with the verdict and confidence
+    SALT=${6:-$(openssl rand -hex 32)}
+    echo "🔒 Committing sealed review (salt: $SALT)..."
+    HASH=$(stellar contract invoke \
+      --id $REVIEW_CONSENSUS \
+      --source deployer \
+      --network testnet \
+      --send=no \
+      -- \
+      compute_commitment \
+      --expert "$2" \
+      --claim_id "$3" \
+      --round "$(stellar contract invoke --id $REVIEW_CONSENSUS --source deployer --network testnet --send=no -- get_claim_round --claim_id "$3")" \
+      --verdict "$4" \
+      --confidence "$5" \
+      --salt "$SALT" | tr -d '"')
+    stellar contract invoke \
+      --id $REVIEW_CONSENSUS \
+      --source deployer \
+      --network testnet \
+      -- \
+      commit_review \
+      --expert "$2" \
+      --claim_id "$3" \
+      --hash "$HASH" \
+      --stake_amount "$7"
+    ;;
+
+  "reveal-review")
+    echo "🔓 Revealing review..."
     stellar contract invoke \
       --id $REVIEW_CONSENSUS \
       --source deployer \
       --network testnet \
       -- \
-      submit_review \
+      reveal_review \
       --expert "$2" \
       --claim_id "$3" \
       --verdict "$4" \
       --confidence "$5" \
-      --comment "$6"
+      --salt "$6" \
+      --reasoning "$7"
     ;;
 
   "get-review")
@@ -162,8 +192,13 @@ case "$1" in
     echo "    Example: ./invoke_contracts.sh expert-count"
     echo ""
     echo "REVIEW CONSENSUS:"
-    echo "  submit-review <expert> <claim_id> <verdict> <confidence> <comment>"
-    echo "    Example: ./invoke_contracts.sh submit-review $ADMIN_ADDRESS 1 true 90 \"This is accurate\""
+    echo "  commit-review <expert> <claim_id> <verdict> <confidence> <salt|\"\"> <stake_amount>"
+    echo "    Seals the vote; an empty salt generates one. The commitment hash is computed by simulation only."
+    echo "    Example: ./invoke_contracts.sh commit-review $ADMIN_ADDRESS 1 True 90 \"\" 100000000"
+    echo ""
+    echo "  reveal-review <expert> <claim_id> <verdict> <confidence> <salt> <reasoning>"
+    echo "    Run after the commit phase closes, with the salt printed by commit-review"
+    echo "    Example: ./invoke_contracts.sh reveal-review $ADMIN_ADDRESS 1 True 90 <salt> \"This is accurate\""
     echo ""
     echo "  get-review <claim_id> <expert>"
     echo "    Example: ./invoke_contracts.sh get-review 1 $ADMIN_ADDRESS"
@@ -7,7 +7,7 @@ interface Review {
   id: number;
   claim_id: number;
   expert: string;
-  verdict: string;
+  verdict: [string];
   reasoning: string;
   confidence: number;
   stake_amount: string;
@@ -41,13 +41,31 @@ interface Claim {
   review_count: number;
 }
 
+// Every verdict an expert can reach, in display order
+const VERDICTS: { [verdict: string]: string } = {
+  True: '✅ TRUE',
+  False: '❌ FALSE',
+  Misleading: '⚠️ MISLEADING',
+  PartiallyTrue: '◐ PARTIALLY TRUE',
+  Unverifiable: '❔ UNVERIFIABLE',
+};
+
+// Contract enums decode as arrays: the variant name followed by its fields
+interface VerdictTally {
+  verdict: [string];
+  total_stake: string;
+  weight: string;
+}
+
 interface ConsensusResult {
   claim_id: number;
-  final_verdict: string;
-  total_stake_true: string;
-  total_stake_false: string;
+  round: number;
+  outcome: ['Resolved', [string]] | ['Inconclusive'];
+  strategy: [string];
+  tallies: VerdictTally[];
   confidence_percentage: number;
   is_finalized: boolean;
+  appeal_deadline: number;
 }
 
 interface ClaimDetailProps {
@@ -248,6 +266,26 @@ const ClaimDetail: React.FC<ClaimDetailProps> = ({ walletAddress }) => {
     ];
   };
 
+  // Verdict the round resolved to, or null when it was inconclusive
+  const getResolvedVerdict = (result: ConsensusResult): string | null => {
+    return result.outcome[0] === 'Resolved' ? result.outcome[1][0] : null;
+  };
+
+  const getVerdictLabel = (verdict: string): string => {
+    return VERDICTS[verdict] || verdict.toUpperCase();
+  };
+
+  // Stake behind each verdict, including verdicts no reviewer chose
+  const getVerdictStakes = (result: ConsensusResult): { verdict: string; stake: number; percentage: number }[] => {
+    const total = result.tallies.reduce((sum, t) => sum + parseFloat(t.total_stake), 0);
+
+    return Object.keys(VERDICTS).map(verdict => {
+      const tally = result.tallies.find(t => t.verdict[0] === verdict);
+      const stake = tally ? parseFloat(tally.total_stake) : 0;
+      return { verdict, stake, percentage: total > 0 ? (stake / total) * 100 : 0 };
+    });
+  };
+
   const getAccuracyPercentage = (expert: ExpertInfo): number => {
     if (expert.total_reviews === 0) return 0;
     return Math.round((expert.correct_reviews / expert.total_reviews) * 100);
@@ -288,6 +326,8 @@ const ClaimDetail: React.FC<ClaimDetailProps> = ({ walletAddress }) => {
     );
   }
 
+  const resolvedVerdict = consensus ? getResolvedVerdict(consensus) : null;
+
   return (
     <div className="claim-detail-page">
       <Link to="/claims" className="back-link">← Back to Claims</Link>
@@ -327,9 +367,13 @@ const ClaimDetail: React.FC<ClaimDetailProps> = ({ walletAddress }) => {
           <h2>⚖️ Consensus Result</h2>
           <div className="consensus-display">
             <div className="consensus-verdict">
-              <div className={`verdict-badge ${consensus.final_verdict.toLowerCase()}`}>
-                {consensus.final_verdict === 'True' ? '✅ TRUE' : '❌ FALSE'}
-              </div>
+              {resolvedVerdict ? (
+                <div className={`verdict-badge ${resolvedVerdict.toLowerCase()}`}>
+                  {getVerdictLabel(resolvedVerdict)}
+                </div>
+              ) : (
+                <div className="verdict-badge inconclusive">⚖️ INCONCLUSIVE</div>
+              )}
               <p className="confidence-text">
                 {consensus.confidence_percentage}% Confidence
               </p>
@@ -338,32 +382,18 @@ const ClaimDetail: React.FC<ClaimDetailProps> = ({ walletAddress }) => {
             <div className="stake-distribution">
               <h3>Stake Distribution</h3>
               <div className="stake-bars">
-                <div className="stake-bar">
-                  <span className="stake-label">True</span>
-                  <div className="stake-bar-bg">
-                    <div 
-                      className="stake-bar-fill true"
-                      style={{
-                        width: `${(parseFloat(consensus.total_stake_true) / 
-                          (parseFloat(consensus.total_stake_true) + parseFloat(consensus.total_stake_false)) * 100)}%`
-                      }}
-                    />
-                  </div>
-                  <span className="stake-amount">{formatXLM(consensus.total_stake_true)} XLM</span>
-                </div>
-                <div className="stake-bar">
-                  <span className="stake-label">False</span>
-                  <div className="stake-bar-bg">
-                    <div 
-                      className="stake-bar-fill false"
-                      style={{
-                        width: `${(parseFloat(consensus.total_stake_false) / 
-                          (parseFloat(consensus.total_stake_true) + parseFloat(consensus.total_stake_false)) * 100)}%`
-                      }}
-                    />
+                {getVerdictStakes(consensus).map(({ verdict, stake, percentage }) => (
+                  <div className="stake-bar" key={verdict}>
+                    <span className="stake-label">{verdict === 'PartiallyTrue' ? 'Partially True' : verdict}</span>
+                    <div className="stake-bar-bg">
+                      <div 
+                        className={`stake-bar-fill ${verdict.toLowerCase()}`}
+                        style={{ width: `${percentage}%` }}
+                      />
+                    </div>
+                    <span className="stake-amount">{formatXLM(stake.toString())} XLM</span>
                   </div>
-                  <span className="stake-amount">{formatXLM(consensus.total_stake_false)} XLM</span>
-                </div>
+                ))}
               </div>
             </div>
           </div>
@@ -517,8 +547,8 @@ const ClaimDetail: React.FC<ClaimDetailProps> = ({ walletAddress }) => {
                     </div>
                     
                     <div className="review-meta">
-                      <div className={`review-verdict ${review.verdict.toLowerCase()}`}>
-                        {review.verdict === 'True' ? '✅ TRUE' : '❌ FALSE'}
+                      <div className={`review-verdict ${review.verdict[0].toLowerCase()}`}>
+                        {getVerdictLabel(review.verdict[0])}
                       </div>
                       <div className="review-timestamp">
                         📅 {formatDateTime(review.timestamp)}
@@ -1,5 +1,5 @@
 import React, { useState, useEffect } from 'react';
-import { registerExpert, isExpert, getExpert, submitReview } from '../utils/contracts';
+import { registerExpert, isExpert, getExpert, commitReview, revealReview, getPendingReveals, ReviewSecret } from '../utils/contracts';
 import './ExpertDashboard.css';
 
 interface ExpertDashboardProps {
@@ -34,6 +34,7 @@ const ExpertDashboard: React.FC<ExpertDashboardProps> = ({ walletAddress }) => {
   const [confidence, setConfidence] = useState(50);
   const [reasoning, setReasoning] = useState('');
   const [reviewStake, setReviewStake] = useState('10');
+  const [pendingReveals, setPendingReveals] = useState<ReviewSecret[]>([]);
 
   useEffect(() => {
     checkExpertStatus();
@@ -49,6 +50,7 @@ const ExpertDashboard: React.FC<ExpertDashboardProps> = ({ walletAddress }) => {
       console.log('Checking expert status for:', walletAddress);
       const registered = await isExpert(walletAddress);
       setIsRegistered(registered);
+      setPendingReveals(getPendingReveals(walletAddress));
 
       if (registered) {
         const profile = await getExpert(walletAddress);
@@ -148,7 +150,7 @@ const ExpertDashboard: React.FC<ExpertDashboardProps> = ({ walletAddress }) => {
 
     try {
       setLoading(true);
-      console.log('Submitting review:', {
+      console.log('Committing review:', {
         claimId: selectedClaim,
         verdict,
         confidence,
@@ -158,7 +160,7 @@ const ExpertDashboard: React.FC<ExpertDashboardProps> = ({ walletAddress }) => {
 
       const stakeAmount = BigInt(parseFloat(reviewStake) * 10_000_000); // Convert XLM to stroops
       
-      const txHash = await submitReview(
+      const txHash = await commitReview(
         walletAddress,
         BigInt(selectedClaim),
         verdict,
@@ -167,7 +169,7 @@ const ExpertDashboard: React.FC<ExpertDashboardProps> = ({ walletAddress }) => {
         stakeAmount
       );
 
-      alert(`✅ Review submitted successfully!\n\nTransaction Hash: ${txHash}\n\nYour review is now on-chain!`);
+      alert(`✅ Review committed successfully!\n\nTransaction Hash: ${txHash}\n\nYour verdict stays sealed until you reveal it after the commit phase closes. Reveal from this browser, or your stake is forfeited.`);
       
       setSelectedClaim(null);
       setConfidence(50);
@@ -184,6 +186,26 @@ const ExpertDashboard: React.FC<ExpertDashboardProps> = ({ walletAddress }) => {
     }
   };
 
+  const handleReveal = async (claimId: string) => {
+    if (!walletAddress) {
+      return;
+    }
+
+    try {
+      setLoading(true);
+      const txHash = await revealReview(walletAddress, BigInt(claimId));
+
+      alert(`✅ Review revealed!\n\nTransaction Hash: ${txHash}`);
+
+      await checkExpertStatus();
+    } catch (error: any) {
+      console.error('Error revealing review:', error);
+      alert(`Failed to reveal review: ${error.message || 'Unknown error'}`);
+    } finally {
+      setLoading(false);
+    }
+  };
+
   // Mock pending claims
   const pendingClaims = [
     { id: 1, text: 'New medical treatment shows promise', category: 'Health' },
@@ -202,15 +224,15 @@ const ExpertDashboard: React.FC<ExpertDashboardProps> = ({ walletAddress }) => {
     );
   }
 
+  // Values are the category slugs registered on-chain, as used by SubmitClaim
   const expertiseOptions = [
-    'Science & Technology',
-    'Politics & Governance',
-    'Health & Medicine',
-    'Climate & Environment',
-    'Economics & Finance',
-    'History & Facts',
-    'Social Issues',
-    'Other'
+    { value: 'science', label: 'Science' },
+    { value: 'politics', label: 'Politics' },
+    { value: 'health', label: 'Health' },
+    { value: 'technology', label: 'Technology' },
+    { value: 'environment', label: 'Environment' },
+    { value: 'economics', label: 'Economics' },
+    { value: 'other', label: 'Other' }
   ];
 
   if (loading && !isRegistered) {
@@ -261,13 +283,13 @@ const ExpertDashboard: React.FC<ExpertDashboardProps> = ({ walletAddress }) => {
             <label className="form-label">Areas of Expertise (Select at least one)</label>
             <div className="category-grid">
               {expertiseOptions.map((category) => (
-                <label key={category} className="checkbox-label">
+                <label key={category.value} className="checkbox-label">
                   <input
                     type="checkbox"
-                    checked={selectedCategories.includes(category)}
-                    onChange={() => handleCategoryToggle(category)}
+                    checked={selectedCategories.includes(category.value)}
+                    onChange={() => handleCategoryToggle(category.value)}
                   />
-                  <span>{category}</span>
+                  <span>{category.label}</span>
                 </label>
               ))}
             </div>
@@ -412,6 +434,28 @@ const ExpertDashboard: React.FC<ExpertDashboardProps> = ({ walletAddress }) => {
         </div>
       </div>
 
+      {pendingReveals.length > 0 && (
+        <div className="pending-claims-section">
+          <h2>Reviews Awaiting Reveal</h2>
+          <p className="form-hint">Reveal each review after its claim's commit phase closes and before the reveal deadline</p>
+          <div className="claims-list">
+            {pendingReveals.map(secret => (
+              <div key={secret.claimId} className="claim-item">
+                <div className="claim-category-badge">Claim #{secret.claimId}</div>
+                <p className="claim-text">{secret.verdict} · {secret.confidence}% confidence</p>
+                <button
+                  className="btn btn-secondary btn-sm"
+                  onClick={() => handleReveal(secret.claimId)}
+                  disabled={loading}
+                >
+                  Reveal
+                </button>
+              </div>
+            ))}
+          </div>
+        </div>
+      )}
+
       <div className="pending-claims-section">
         <h2>Pending Claims to Review</h2>
         <div className="claims-list">
@@ -433,7 +477,7 @@ const ExpertDashboard: React.FC<ExpertDashboardProps> = ({ walletAddress }) => {
 
       {selectedClaim && (
         <div className="review-form-section">
-          <h2>Submit Review for Claim #{selectedClaim}</h2>
+          <h2>Commit Review for Claim #{selectedClaim}</h2>
           <form onSubmit={handleSubmitReview} className="review-form">
             <div className="form-group">
               <label className="form-label">Your Verdict</label>
@@ -512,7 +556,7 @@ const ExpertDashboard: React.FC<ExpertDashboardProps> = ({ walletAddress }) => {
               className="btn btn-primary"
               disabled={loading}
             >
-              {loading ? 'Submitting...' : `Submit Review (Stake ${reviewStake} XLM)`}
+              {loading ? 'Committing...' : `Commit Sealed Review (Stake ${reviewStake} XLM)`}
             </button>
           </form>
         </div>
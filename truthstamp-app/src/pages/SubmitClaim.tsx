@@ -8,7 +8,7 @@ interface SubmitClaimProps {
 
 const SubmitClaim: React.FC<SubmitClaimProps> = ({ walletAddress }) => {
   const [claimText, setClaimText] = useState('');
-  const [category, setCategory] = useState('Science');
+  const [category, setCategory] = useState('science');
   const [sources, setSources] = useState(['']);
   const [isSubmitting, setIsSubmitting] = useState(false);
 
@@ -55,7 +55,7 @@ const SubmitClaim: React.FC<SubmitClaimProps> = ({ walletAddress }) => {
       
       // Reset form
       setClaimText('');
-      setCategory('Science');
+      setCategory('science');
       setSources(['']);
     } catch (error) {
       console.error('Error submitting claim:', error);
@@ -103,13 +103,13 @@ const SubmitClaim: React.FC<SubmitClaimProps> = ({ walletAddress }) => {
             value={category}
             onChange={(e) => setCategory(e.target.value)}
           >
-            <option>Science</option>
-            <option>Politics</option>
-            <option>Health</option>
-            <option>Technology</option>
-            <option>Environment</option>
-            <option>Economics</option>
-            <option>Other</option>
+            <option value="science">Science</option>
+            <option value="politics">Politics</option>
+            <option value="health">Health</option>
+            <option value="technology">Technology</option>
+            <option value="environment">Environment</option>
+            <option value="economics">Economics</option>
+            <option value="other">Other</option>
           </select>
         </div>
 
@@ -204,81 +204,242 @@ export async function registerExpert(
   }
 }
 
-// Submit a review
-export async function submitReview(
+export type Verdict = 'True' | 'False' | 'Misleading' | 'Unverifiable' | 'PartiallyTrue';
+
+// Byte each verdict contributes to the commitment preimage, as in the contract's compute_commitment
+const VERDICT_BYTES: Record<Verdict, number> = {
+  True: 0,
+  False: 1,
+  Misleading: 2,
+  Unverifiable: 3,
+  PartiallyTrue: 4,
+};
+
+// Sealed review kept in the browser until it is revealed
+export interface ReviewSecret {
+  claimId: string;
+  round: number;
+  verdict: Verdict;
+  reasoning: string;
+  confidence: number;
+  salt: string; // hex
+}
+
+const reviewSecretKey = (walletAddress: string, claimId: bigint) =>
+  `truthstamp:review:${walletAddress}:${claimId.toString()}`;
+
+const toHex = (bytes: Uint8Array) =>
+  Array.from(bytes).map(b => b.toString(16).padStart(2, '0')).join('');
+
+const fromHex = (hex: string) =>
+  new Uint8Array((hex.match(/.{2}/g) || []).map(b => parseInt(b, 16)));
+
+// Unit enum variants are encoded as a vector holding the variant name
+const verdictToScVal = (verdict: Verdict) => xdr.ScVal.scvVec([xdr.ScVal.scvSymbol(verdict)]);
+
+// Commitment hash, computed locally so the verdict and salt never leave the browser before the reveal:
+// sha256(expert (ScVal XDR) || claim_id (u64) || round (u32) || verdict byte || confidence (u32) || salt)
+export async function computeCommitment(
+  walletAddress: string,
+  claimId: bigint,
+  round: number,
+  verdict: Verdict,
+  confidence: number,
+  salt: Uint8Array
+): Promise<Uint8Array> {
+  const expert = new Address(walletAddress).toScVal().toXDR();
+  const preimage = new Uint8Array(expert.length + 8 + 4 + 1 + 4 + salt.length);
+  const view = new DataView(preimage.buffer);
+
+  let offset = 0;
+  preimage.set(expert, offset);
+  offset += expert.length;
+  view.setBigUint64(offset, claimId);
+  offset += 8;
+  view.setUint32(offset, round);
+  offset += 4;
+  view.setUint8(offset, VERDICT_BYTES[verdict]);
+  offset += 1;
+  view.setUint32(offset, confidence);
+  offset += 4;
+  preimage.set(salt, offset);
+
+  return new Uint8Array(await crypto.subtle.digest('SHA-256', preimage));
+}
+
+// Get the review sealed for a claim, if it has not been revealed yet
+export function getReviewSecret(walletAddress: string, claimId: bigint): ReviewSecret | null {
+  const stored = localStorage.getItem(reviewSecretKey(walletAddress, claimId));
+  return stored ? JSON.parse(stored) : null;
+}
+
+// Get every review sealed from this browser that still has to be revealed
+export function getPendingReveals(walletAddress: string): ReviewSecret[] {
+  const prefix = `truthstamp:review:${walletAddress}:`;
+  const secrets: ReviewSecret[] = [];
+
+  for (let i = 0; i < localStorage.length; i++) {
+    const key = localStorage.key(i);
+    if (key && key.startsWith(prefix)) {
+      secrets.push(JSON.parse(localStorage.getItem(key) as string));
+    }
+  }
+
+  return secrets;
+}
+
+// Sign and send a contract call with Freighter
+async function invokeContract(
+  walletAddress: string,
+  contractId: string,
+  method: string,
+  ...args: xdr.ScVal[]
+): Promise<string> {
+  const contract = new Contract(contractId);
+  const sourceAccount = await server.getAccount(walletAddress);
+
+  const transaction = new TransactionBuilder(sourceAccount, {
+    fee: BASE_FEE,
+    networkPassphrase: NETWORK_PASSPHRASE,
+  })
+    .addOperation(contract.call(method, ...args))
+    .setTimeout(180)
+    .build();
+
+  // Prepare and simulate
+  const preparedTransaction = await server.prepareTransaction(transaction);
+
+  // Sign with Freighter
+  const signedXDR = await signTransaction(preparedTransaction.toXDR(), {
+    network: 'TESTNET',
+    networkPassphrase: NETWORK_PASSPHRASE,
+    accountToSign: walletAddress,
+  });
+
+  const signedTransaction = TransactionBuilder.fromXDR(signedXDR, NETWORK_PASSPHRASE);
+
+  // Send transaction
+  const response = await server.sendTransaction(signedTransaction);
+
+  return response.hash;
+}
+
+// Commit a sealed review, locking its stake; the verdict stays hidden until revealReview
+export async function commitReview(
   walletAddress: string,
   claimId: bigint,
-  verdict: 'True' | 'False',
+  verdict: Verdict,
   reasoning: string,
   confidence: number,
   stakeAmount: bigint
 ): Promise<string> {
   try {
-    console.log('🚀 Submitting review to blockchain:', {
+    console.log('🚀 Committing sealed review to blockchain:', {
       contract: REVIEW_CONSENSUS_CONTRACT,
       walletAddress,
       claimId,
-      verdict,
-      confidence,
       stakeAmount
     });
 
-    const contract = new Contract(REVIEW_CONSENSUS_CONTRACT);
-    
-    // Get account details from network
-    const sourceAccount = await server.getAccount(walletAddress);
-    
-    // Convert parameters to ScVal format
-    const expertAddress = new Address(walletAddress);
-    const claimIdScVal = nativeToScVal(claimId, { type: 'u64' });
-    const verdictScVal = xdr.ScVal.scvSymbol(verdict);
-    const reasoningScVal = nativeToScVal(reasoning, { type: 'string' });
-    const confidenceScVal = nativeToScVal(confidence, { type: 'u32' });
-    const stakeScVal = nativeToScVal(stakeAmount, { type: 'i128' });
-    
-    // Build the transaction
-    const transaction = new TransactionBuilder(sourceAccount, {
-      fee: BASE_FEE,
-      networkPassphrase: NETWORK_PASSPHRASE,
-    })
-      .addOperation(
-        contract.call(
-          'submit_review',
-          expertAddress.toScVal(),
-          claimIdScVal,
-          verdictScVal,
-          reasoningScVal,
-          confidenceScVal,
-          stakeScVal
-        )
-      )
-      .setTimeout(180)
-      .build();
-    
-    // Prepare and simulate
-    const preparedTransaction = await server.prepareTransaction(transaction);
-    
-    // Sign with Freighter
-    const signedXDR = await signTransaction(preparedTransaction.toXDR(), {
-      network: 'TESTNET',
-      networkPassphrase: NETWORK_PASSPHRASE,
-      accountToSign: walletAddress,
-    });
-    
-    const signedTransaction = TransactionBuilder.fromXDR(signedXDR, NETWORK_PASSPHRASE);
-    
-    // Send transaction
-    const response = await server.sendTransaction(signedTransaction);
-    
-    console.log('✅ Review submitted! Hash:', response.hash);
-    
-    return response.hash;
+    const round = await getClaimRound(walletAddress, claimId);
+    const salt = crypto.getRandomValues(new Uint8Array(32));
+    const hash = await computeCommitment(walletAddress, claimId, round, verdict, confidence, salt);
+
+    // Keep the vote and salt locally; without them the review cannot be revealed and its stake is forfeited
+    const secret: ReviewSecret = {
+      claimId: claimId.toString(),
+      round,
+      verdict,
+      reasoning,
+      confidence,
+      salt: toHex(salt),
+    };
+    localStorage.setItem(reviewSecretKey(walletAddress, claimId), JSON.stringify(secret));
+
+    const txHash = await invokeContract(
+      walletAddress,
+      REVIEW_CONSENSUS_CONTRACT,
+      'commit_review',
+      new Address(walletAddress).toScVal(),
+      nativeToScVal(claimId, { type: 'u64' }),
+      nativeToScVal(hash, { type: 'bytes' }),
+      nativeToScVal(stakeAmount, { type: 'i128' })
+    );
+
+    console.log('✅ Review committed! Hash:', txHash);
+
+    return txHash;
+  } catch (error) {
+    console.error('Error committing review:', error);
+    throw error;
+  }
+}
+
+// Reveal a review committed from this browser once the claim's commit phase has closed
+export async function revealReview(walletAddress: string, claimId: bigint): Promise<string> {
+  try {
+    const secret = getReviewSecret(walletAddress, claimId);
+    if (!secret) {
+      throw new Error(`No sealed review for claim #${claimId} in this browser`);
+    }
+
+    console.log('🔓 Revealing review:', { contract: REVIEW_CONSENSUS_CONTRACT, walletAddress, claimId });
+
+    const txHash = await invokeContract(
+      walletAddress,
+      REVIEW_CONSENSUS_CONTRACT,
+      'reveal_review',
+      new Address(walletAddress).toScVal(),
+      nativeToScVal(claimId, { type: 'u64' }),
+      verdictToScVal(secret.verdict),
+      nativeToScVal(secret.reasoning, { type: 'string' }),
+      nativeToScVal(secret.confidence, { type: 'u32' }),
+      nativeToScVal(fromHex(secret.salt), { type: 'bytes' })
+    );
+
+    localStorage.removeItem(reviewSecretKey(walletAddress, claimId));
+    console.log('✅ Review revealed! Hash:', txHash);
+
+    return txHash;
   } catch (error) {
-    console.error('Error submitting review:', error);
+    console.error('Error revealing review:', error);
     throw error;
   }
 }
 
+// Get the review window (commit and reveal deadlines) of a claim
+export async function getReviewWindow(walletAddress: string, claimId: bigint): Promise<any> {
+  return simulateRead(walletAddress, REVIEW_CONSENSUS_CONTRACT, 'get_review_window', nativeToScVal(claimId, { type: 'u64' }));
+}
+
+// Get the current review round of a claim (0 until appealed)
+export async function getClaimRound(walletAddress: string, claimId: bigint): Promise<number> {
+  const round = await simulateRead(walletAddress, REVIEW_CONSENSUS_CONTRACT, 'get_claim_round', nativeToScVal(claimId, { type: 'u64' }));
+  return Number(round ?? 0);
+}
+
+// Simulate a read-only contract call and decode its result
+async function simulateRead(walletAddress: string, contractId: string, method: string, ...args: xdr.ScVal[]): Promise<any> {
+  const contract = new Contract(contractId);
+  const sourceAccount = await server.getAccount(walletAddress);
+
+  const transaction = new TransactionBuilder(sourceAccount, {
+    fee: BASE_FEE,
+    networkPassphrase: NETWORK_PASSPHRASE,
+  })
+    .addOperation(contract.call(method, ...args))
+    .setTimeout(180)
+    .build();
+
+  const simulation = await server.simulateTransaction(transaction);
+
+  if (SorobanRpc.Api.isSimulationSuccess(simulation) && simulation.result?.retval) {
+    return scValToNative(simulation.result.retval);
+  }
+
+  return null;
+}
+
 // Get expert profile
 export async function getExpert(walletAddress: string): Promise<any> {
   try {